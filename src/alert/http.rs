@@ -1,12 +1,19 @@
-use std::fs;
 use std::{collections::HashMap, time::Duration};
 
 use anyhow::{Result, anyhow};
 
-use crate::helper::{HTTP_OK_CODES, HttpMethod};
+use crate::config;
+use crate::helper::auth::TokenClient;
+use crate::helper::tls::ClientTls;
+use crate::helper::{
+    HTTP_OK_CODES, HttpAuth, HttpMethod, HttpProxy, http_tls, load_body, proxy_from_config,
+};
 
 #[derive(Debug, Clone)]
 pub struct HttpAlert {
+    // The name of the service this alert belongs to (used for body placeholders).
+    pub service: String,
+
     pub method: HttpMethod,
     pub url: String,
 
@@ -17,37 +24,68 @@ pub struct HttpAlert {
 
     pub headers: Option<HashMap<String, String>>,
     pub is_insecure: bool,
+
+    pub auth: Option<HttpAuth>,
+
+    pub tls: ClientTls,
+
+    pub proxy: Option<HttpProxy>,
 }
 
 impl HttpAlert {
-    pub fn new(
-        method: HttpMethod,
-        url: String,
-        timeout: u64,
-
-        body: Option<String>,
-        body_is_file: bool,
-
-        headers: Option<HashMap<String, String>>,
-        is_insecure: bool,
-    ) -> Self {
-        Self {
-            method,
-            url,
-            timeout,
-            body,
-            body_is_file,
-            headers,
-            is_insecure,
-        }
+    pub fn from_config(service: &str, http: &config::HttpAlert) -> Result<HttpAlert> {
+        Ok(HttpAlert {
+            service: service.to_string(),
+
+            method: HttpMethod::from_string(http.method.as_str()),
+            url: http.url.clone(),
+            timeout: http.timeout,
+
+            body: http.body.clone(),
+            body_is_file: http.body_is_file,
+
+            headers: http.headers.clone(),
+            is_insecure: http.is_insecure,
+
+            auth: match &http.auth {
+                Some(auth) => Some(HttpAuth::from_config(auth)?),
+                None => None,
+            },
+
+            tls: ClientTls {
+                ca_file: http.ca_file.clone(),
+                client_cert: http.client_cert.clone(),
+                client_key: http.client_key.clone(),
+                pins: http
+                    .pins
+                    .iter()
+                    .map(|pin| ClientTls::parse_pin(pin))
+                    .collect::<Result<Vec<_>>>()?,
+            },
+
+            proxy: match &http.proxy {
+                Some(proxy) => Some(proxy_from_config(proxy)?),
+                None => None,
+            },
+        })
     }
 
     pub async fn exec(&self) -> Result<()> {
         // Build client.
-        let cl = reqwest::Client::builder()
+        let mut builder = reqwest::Client::builder()
             .danger_accept_invalid_certs(self.is_insecure)
-            .danger_accept_invalid_hostnames(self.is_insecure)
-            .build()?;
+            .danger_accept_invalid_hostnames(self.is_insecure);
+
+        // Custom CAs, client certs, and pins need our own rustls config.
+        if !self.tls.is_empty() {
+            builder = builder.tls_backend_preconfigured(http_tls(&self.tls, self.is_insecure)?);
+        }
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.proxy.clone());
+        }
+
+        let cl = builder.build()?;
 
         // Create request based off of method.
         let mut req = match self.method {
@@ -63,26 +101,14 @@ impl HttpAlert {
             req = req.timeout(Duration::from_secs(self.timeout));
         }
 
-        // If we have body payload, parse it.
-        let body: Option<String> = match &self.body {
-            Some(body) => {
-                if self.body_is_file {
-                    match fs::read_to_string(body.clone()) {
-                        Ok(res) => Some(res),
-                        Err(e) => {
-                            return Err(anyhow!("Failed to read body from file: {}", e));
-                        }
-                    };
-                }
-
-                Some(body.clone())
-            }
-            None => None,
-        };
-
-        // If we have a valid body, we need to use it!
-        if let Some(body) = body {
-            req = req.body(body);
+        // If we have a body, load it and fill in its placeholders.
+        if let Some(body) = &self.body {
+            req = req.body(load_body(
+                body,
+                self.body_is_file,
+                &self.service,
+                HashMap::new(),
+            )?);
         }
 
         // If we have headers, append them now.
@@ -92,7 +118,16 @@ impl HttpAlert {
             }
         }
 
-        let res = req.send().await;
+        let token_cl = TokenClient {
+            proxy: self.proxy.as_ref().map(|proxy| &proxy.proxy),
+            is_insecure: self.is_insecure,
+            ca_file: self.tls.ca_file.as_deref(),
+        };
+
+        let res = match &self.auth {
+            Some(auth) => auth.send(&token_cl, req, self.timeout).await?,
+            None => req.send().await,
+        };
 
         match res {
             Ok(res) => {
@@ -119,3 +154,25 @@ impl HttpAlert {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hides_proxy_password() {
+        let cfg: config::HttpAlert = serde_json::from_value(serde_json::json!({
+            "url": "http://example.com",
+            "proxy": {
+                "url": "http://proxy.example.com:3128",
+                "username": "user",
+                "password": "hunter2",
+            },
+        }))
+        .unwrap();
+
+        let alert = HttpAlert::from_config("test", &cfg).unwrap();
+
+        assert!(!format!("{:?}", alert).contains("hunter2"));
+    }
+}
@@ -0,0 +1,142 @@
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+use anyhow::{Result, anyhow};
+
+use crate::check::CheckRes;
+
+#[derive(Debug, Clone)]
+pub struct DiskCheck {
+    pub mounts: Vec<String>,
+
+    pub warn_used_pct: Option<f64>,
+    pub fail_used_pct: Option<f64>,
+
+    pub warn_free_mb: Option<u64>,
+    pub fail_free_mb: Option<u64>,
+
+    pub warn_inodes_pct: Option<f64>,
+    pub fail_inodes_pct: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+struct DiskUsage {
+    used_pct: f64,
+    free_mb: u64,
+
+    // Some filesystems (e.g. btrfs) don't report inodes.
+    inodes_pct: Option<f64>,
+}
+
+impl DiskCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let mut fails: Vec<String> = Vec::new();
+        let mut warns: Vec<String> = Vec::new();
+
+        for mount in self.mounts.iter() {
+            let usage = Self::statvfs(mount)?;
+
+            if let Some(pct) = self.fail_used_pct
+                && usage.used_pct >= pct
+            {
+                fails.push(format!(
+                    "{} is {:.1}% used (fail at {}%)",
+                    mount, usage.used_pct, pct
+                ));
+            } else if let Some(pct) = self.warn_used_pct
+                && usage.used_pct >= pct
+            {
+                warns.push(format!(
+                    "{} is {:.1}% used (warn at {}%)",
+                    mount, usage.used_pct, pct
+                ));
+            }
+
+            if let Some(mb) = self.fail_free_mb
+                && usage.free_mb < mb
+            {
+                fails.push(format!(
+                    "{} has {}MB free (fail under {}MB)",
+                    mount, usage.free_mb, mb
+                ));
+            } else if let Some(mb) = self.warn_free_mb
+                && usage.free_mb < mb
+            {
+                warns.push(format!(
+                    "{} has {}MB free (warn under {}MB)",
+                    mount, usage.free_mb, mb
+                ));
+            }
+
+            if let Some(inodes_pct) = usage.inodes_pct {
+                if let Some(pct) = self.fail_inodes_pct
+                    && inodes_pct >= pct
+                {
+                    fails.push(format!(
+                        "{} inodes are {:.1}% used (fail at {}%)",
+                        mount, inodes_pct, pct
+                    ));
+                } else if let Some(pct) = self.warn_inodes_pct
+                    && inodes_pct >= pct
+                {
+                    warns.push(format!(
+                        "{} inodes are {:.1}% used (warn at {}%)",
+                        mount, inodes_pct, pct
+                    ));
+                }
+            }
+        }
+
+        if !fails.is_empty() {
+            return Err(anyhow!("{}", fails.join("; ")));
+        }
+
+        let mut res = CheckRes::default();
+
+        if !warns.is_empty() {
+            res.warn = Some(warns.join("; "));
+        }
+
+        Ok(res)
+    }
+
+    fn statvfs(mount: &str) -> Result<DiskUsage> {
+        let path = CString::new(mount)?;
+        let mut stat = MaybeUninit::<libc::statvfs>::uninit();
+
+        // SAFETY: The path is a valid C string and stat is only read after a successful call.
+        let stat = unsafe {
+            if libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) != 0 {
+                return Err(anyhow!(
+                    "Failed to stat {}: {}",
+                    mount,
+                    std::io::Error::last_os_error()
+                ));
+            }
+
+            stat.assume_init()
+        };
+
+        // Match df by calculating usage against the space available to unprivileged users.
+        let used = (stat.f_blocks - stat.f_bfree) as f64;
+        let avail = stat.f_bavail as f64;
+
+        let used_pct = match used + avail > 0.0 {
+            true => used / (used + avail) * 100.0,
+            false => 0.0,
+        };
+
+        let free_mb = stat.f_bavail as u64 * stat.f_frsize as u64 / 1024 / 1024;
+
+        let inodes_pct = match stat.f_files {
+            0 => None,
+            files => Some((files - stat.f_ffree) as f64 / files as f64 * 100.0),
+        };
+
+        Ok(DiskUsage {
+            used_pct,
+            free_mb,
+            inodes_pct,
+        })
+    }
+}
@@ -0,0 +1,415 @@
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, anyhow};
+use hickory_proto::op::{Message, MessageType, OpCode, Query, ResponseCode};
+use hickory_proto::rr::{Name, RData, RecordType};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::time::timeout;
+
+use crate::check::CheckRes;
+
+// The mnemonics we accept for rcodes (anything else has to be given as a number).
+const RCODES: [(&str, ResponseCode); 11] = [
+    ("NOERROR", ResponseCode::NoError),
+    ("FORMERR", ResponseCode::FormErr),
+    ("SERVFAIL", ResponseCode::ServFail),
+    ("NXDOMAIN", ResponseCode::NXDomain),
+    ("NOTIMP", ResponseCode::NotImp),
+    ("REFUSED", ResponseCode::Refused),
+    ("YXDOMAIN", ResponseCode::YXDomain),
+    ("YXRRSET", ResponseCode::YXRRSet),
+    ("NXRRSET", ResponseCode::NXRRSet),
+    ("NOTAUTH", ResponseCode::NotAuth),
+    ("NOTZONE", ResponseCode::NotZone),
+];
+
+#[derive(Debug, Clone)]
+pub struct DnsCheck {
+    pub server: SocketAddr,
+
+    pub name: Name,
+    pub record_type: RecordType,
+
+    pub timeout: u64,
+
+    pub recursion: bool,
+
+    pub rcode: ResponseCode,
+    pub answers: Option<Vec<String>>,
+    pub max_query_time: Option<u32>,
+}
+
+impl DnsCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let query = self.build_query()?;
+
+        let now = Instant::now();
+
+        let res = match timeout(Duration::from_secs(self.timeout), self.send(&query)).await {
+            Ok(res) => res?,
+            Err(_) => {
+                return Err(anyhow!(
+                    "DNS query to {} timed out ({} secs)",
+                    self.server,
+                    self.timeout
+                ));
+            }
+        };
+
+        let query_time = now.elapsed().as_millis() as u32;
+
+        let rcode = res.metadata.response_code;
+
+        if rcode != self.rcode {
+            return Err(anyhow!(
+                "DNS query for {} {} returned {} (expected {})",
+                self.name,
+                self.record_type,
+                Self::rcode_name(rcode),
+                Self::rcode_name(self.rcode)
+            ));
+        }
+
+        if let Some(expected) = &self.answers {
+            // Only look at answers of the type we asked for (e.g. skip CNAMEs when querying A).
+            let mut answers: Vec<String> = res
+                .answers
+                .iter()
+                .filter(|record| record.record_type() == self.record_type)
+                .map(|record| Self::format_rdata(&record.data))
+                .collect();
+
+            let mut expected: Vec<String> = expected.iter().map(|a| self.normalize(a)).collect();
+
+            answers.sort();
+            expected.sort();
+
+            if answers != expected {
+                return Err(anyhow!(
+                    "DNS answers for {} {} don't match: got [{}], expected [{}]",
+                    self.name,
+                    self.record_type,
+                    answers.join(", "),
+                    expected.join(", ")
+                ));
+            }
+        }
+
+        if let Some(max_query_time) = self.max_query_time
+            && query_time > max_query_time
+        {
+            return Err(anyhow!(
+                "DNS query for {} {} took {}ms (max {}ms)",
+                self.name,
+                self.record_type,
+                query_time,
+                max_query_time
+            ));
+        }
+
+        Ok(CheckRes {
+            lats: vec![query_time],
+            ..Default::default()
+        })
+    }
+
+    pub fn parse_rcode(rcode: &str) -> Result<ResponseCode> {
+        if let Some((_, code)) = RCODES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(rcode.trim()))
+        {
+            return Ok(*code);
+        }
+
+        match rcode.trim().parse::<u16>() {
+            Ok(code) => Ok(code.into()),
+            Err(_) => Err(anyhow!("Invalid DNS rcode: {}", rcode)),
+        }
+    }
+
+    fn rcode_name(rcode: ResponseCode) -> String {
+        match RCODES.iter().find(|(_, code)| *code == rcode) {
+            Some((name, _)) => name.to_string(),
+            None => format!("rcode {}", u16::from(rcode)),
+        }
+    }
+
+    fn build_query(&self) -> Result<Message> {
+        // The ID doesn't need to be cryptographically random for a health check.
+        let id = SystemTime::now().duration_since(UNIX_EPOCH)?.subsec_nanos() as u16;
+
+        let mut msg = Message::new(id, MessageType::Query, OpCode::Query);
+
+        msg.metadata.recursion_desired = self.recursion;
+        // Names are always absolute on the wire, so mark ours as such to compare it with the reply's.
+        let mut name = self.name.clone();
+
+        name.set_fqdn(true);
+
+        msg.add_query(Query::query(name, self.record_type));
+
+        Ok(msg)
+    }
+
+    // Whether a message is the reply to our query (and not a stray or spoofed datagram).
+    fn is_reply(query: &Message, res: &Message) -> bool {
+        res.metadata.id == query.metadata.id
+            && res.metadata.message_type == MessageType::Response
+            && res.queries == query.queries
+    }
+
+    async fn send(&self, query: &Message) -> Result<Message> {
+        let bind_addr = match self.server {
+            SocketAddr::V4(_) => "0.0.0.0:0",
+            SocketAddr::V6(_) => "[::]:0",
+        };
+
+        let sock = UdpSocket::bind(bind_addr).await?;
+
+        let bytes = query.to_vec()?;
+
+        sock.connect(self.server).await?;
+        sock.send(&bytes).await?;
+
+        let mut buf = vec![0u8; 4096];
+
+        // Keep reading until our reply shows up (the caller's timeout bounds this).
+        let res = loop {
+            let n = match sock.recv(&mut buf).await {
+                Ok(n) => n,
+                Err(e) => return Err(anyhow!("DNS query to {} failed: {}", self.server, e)),
+            };
+
+            if let Ok(res) = Message::from_vec(&buf[..n])
+                && Self::is_reply(query, &res)
+            {
+                break res;
+            }
+        };
+
+        // If the response was truncated, we need to retry over TCP.
+        if res.metadata.truncation {
+            return self.send_tcp(query).await;
+        }
+
+        Ok(res)
+    }
+
+    async fn send_tcp(&self, query: &Message) -> Result<Message> {
+        let bytes = query.to_vec()?;
+
+        let mut stream = TcpStream::connect(self.server).await?;
+
+        // DNS over TCP prefixes each message with a two byte length.
+        stream
+            .write_all(&(bytes.len() as u16).to_be_bytes())
+            .await?;
+        stream.write_all(&bytes).await?;
+
+        let len = stream.read_u16().await? as usize;
+        let mut buf = vec![0u8; len];
+
+        stream.read_exact(&mut buf).await?;
+
+        let res = Message::from_vec(&buf)?;
+
+        if !Self::is_reply(query, &res) {
+            return Err(anyhow!(
+                "DNS reply from {} doesn't match our query",
+                self.server
+            ));
+        }
+
+        Ok(res)
+    }
+
+    fn format_rdata(data: &RData) -> String {
+        match data {
+            RData::A(a) => a.to_string(),
+            RData::AAAA(aaaa) => aaaa.to_string(),
+            RData::CNAME(cname) => Self::normalize_name(&cname.0.to_ascii()),
+            RData::MX(mx) => format!(
+                "{} {}",
+                mx.preference,
+                Self::normalize_name(&mx.exchange.to_ascii())
+            ),
+            RData::SRV(srv) => format!(
+                "{} {} {} {}",
+                srv.priority,
+                srv.weight,
+                srv.port,
+                Self::normalize_name(&srv.target.to_ascii())
+            ),
+            RData::TXT(txt) => txt.to_string(),
+            data => data.to_string(),
+        }
+    }
+
+    // Formats an expected answer the same way we format the answers we receive.
+    fn normalize(&self, answer: &str) -> String {
+        match self.record_type {
+            RecordType::A | RecordType::AAAA => match answer.trim().parse::<IpAddr>() {
+                Ok(ip) => ip.to_string(),
+                Err(_) => answer.to_string(),
+            },
+            RecordType::CNAME | RecordType::MX | RecordType::SRV => {
+                let mut parts: Vec<&str> = answer.split_whitespace().collect();
+
+                match parts.pop() {
+                    Some(name) => {
+                        let name = Self::normalize_name(name);
+
+                        parts.push(&name);
+
+                        parts.join(" ")
+                    }
+                    None => answer.to_string(),
+                }
+            }
+            _ => answer.to_string(),
+        }
+    }
+
+    // Names are compared case-insensitively and without the trailing dot.
+    fn normalize_name(name: &str) -> String {
+        name.trim_end_matches('.').to_lowercase()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use hickory_proto::rr::Record;
+    use hickory_proto::rr::rdata::A;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    const ANSWER: Ipv4Addr = Ipv4Addr::new(192, 0, 2, 1);
+
+    fn reply(query: &Message, rcode: ResponseCode, ip: Ipv4Addr) -> Message {
+        let mut res = Message::new(query.metadata.id, MessageType::Response, OpCode::Query);
+
+        res.metadata.response_code = rcode;
+        res.add_queries(query.queries.clone());
+
+        if rcode == ResponseCode::NoError {
+            for q in query.queries.iter() {
+                res.add_answer(Record::from_rdata(q.name().clone(), 60, RData::A(A(ip))));
+            }
+        }
+
+        res
+    }
+
+    // A nameserver on UDP and TCP (same port) that answers every A query with ANSWER.
+    // Each UDP reply is preceded by one with the wrong ID and answer, which must be ignored.
+    async fn stub(rcode: ResponseCode, truncate: bool) -> SocketAddr {
+        let udp = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = udp.local_addr().unwrap();
+        let tcp = TcpListener::bind(addr).await.unwrap();
+
+        tokio::spawn(async move {
+            let mut buf = vec![0u8; 4096];
+
+            loop {
+                let (n, peer) = udp.recv_from(&mut buf).await.unwrap();
+                let query = Message::from_vec(&buf[..n]).unwrap();
+
+                let mut decoy = reply(&query, rcode, Ipv4Addr::new(192, 0, 2, 99));
+                decoy.metadata.id = query.metadata.id.wrapping_add(1);
+
+                let mut res = reply(&query, rcode, ANSWER);
+
+                if truncate {
+                    res.answers.clear();
+                    res.metadata.truncation = true;
+                }
+
+                udp.send_to(&decoy.to_vec().unwrap(), peer).await.unwrap();
+                udp.send_to(&res.to_vec().unwrap(), peer).await.unwrap();
+            }
+        });
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = tcp.accept().await.unwrap();
+
+                let len = stream.read_u16().await.unwrap() as usize;
+                let mut buf = vec![0u8; len];
+
+                stream.read_exact(&mut buf).await.unwrap();
+
+                let query = Message::from_vec(&buf).unwrap();
+                let res = reply(&query, rcode, ANSWER).to_vec().unwrap();
+
+                stream
+                    .write_all(&(res.len() as u16).to_be_bytes())
+                    .await
+                    .unwrap();
+                stream.write_all(&res).await.unwrap();
+            }
+        });
+
+        addr
+    }
+
+    fn check(server: SocketAddr, answers: &[&str]) -> DnsCheck {
+        DnsCheck {
+            server,
+            name: Name::from_utf8("example.com").unwrap(),
+            record_type: RecordType::A,
+            timeout: 2,
+            recursion: true,
+            rcode: ResponseCode::NoError,
+            answers: Some(answers.iter().map(|a| a.to_string()).collect()),
+            max_query_time: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn matches_answers() {
+        let server = stub(ResponseCode::NoError, false).await;
+
+        assert!(check(server, &["192.0.2.1"]).exec().await.is_ok());
+
+        let err = check(server, &["192.0.2.2"]).exec().await.unwrap_err();
+
+        assert!(err.to_string().contains("got [192.0.2.1]"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn fails_on_rcode_mismatch() {
+        let server = stub(ResponseCode::NXDomain, false).await;
+
+        let err = check(server, &[]).exec().await.unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("returned NXDOMAIN (expected NOERROR)"),
+            "{}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_truncated_replies_over_tcp() {
+        let server = stub(ResponseCode::NoError, true).await;
+
+        assert!(check(server, &["192.0.2.1"]).exec().await.is_ok());
+    }
+
+    #[test]
+    fn parses_rcodes() {
+        assert_eq!(
+            DnsCheck::parse_rcode("nxdomain").unwrap(),
+            ResponseCode::NXDomain
+        );
+        assert_eq!(DnsCheck::parse_rcode("5").unwrap(), ResponseCode::Refused);
+        assert!(DnsCheck::parse_rcode("NOPE").is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use regex::Regex;
+
+use tokio::process::Command;
+use tokio::time::timeout;
+
+use crate::helper::Secret;
+
+// The max amount of output we include in error messages.
+const OUTPUT_MAX_LEN: usize = 256;
+
+#[derive(Debug, Clone)]
+pub struct ExecCheck {
+    pub program: String,
+    pub args: Vec<String>,
+
+    // Values may hold credentials (e.g. an API token), so they're kept out of Debug.
+    pub env: Option<HashMap<String, Secret>>,
+    pub cwd: Option<String>,
+
+    pub timeout: u64,
+
+    pub exit_codes: Vec<i32>,
+
+    pub stdout: Option<Regex>,
+    pub stderr: Option<Regex>,
+}
+
+impl ExecCheck {
+    pub async fn exec(&self) -> Result<()> {
+        let mut cmd = Command::new(&self.program);
+
+        cmd.args(&self.args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            // Run it in its own process group so a timeout also kills anything it started.
+            .process_group(0)
+            .kill_on_drop(true);
+
+        if let Some(env) = &self.env {
+            cmd.envs(env.iter().map(|(key, val)| (key, val.expose())));
+        }
+
+        if let Some(cwd) = &self.cwd {
+            cmd.current_dir(cwd);
+        }
+
+        let child = match cmd.spawn() {
+            Ok(child) => child,
+            Err(e) => return Err(anyhow!("Failed to run {}: {}", self.program, e)),
+        };
+
+        // The child leads its process group, so the group's ID is the child's PID.
+        let pgid = child.id();
+
+        let output =
+            match timeout(Duration::from_secs(self.timeout), child.wait_with_output()).await {
+                Ok(Ok(output)) => output,
+                Ok(Err(e)) => return Err(anyhow!("Failed to run {}: {}", self.program, e)),
+                Err(_) => {
+                    if let Some(pgid) = pgid {
+                        // SAFETY: kill() has no memory-safety requirements. The child was
+                        // spawned with process_group(0), so it leads a group whose ID is its PID.
+                        // It's still owned by the timed-out future (alive until the end of this
+                        // match) and hasn't been reaped, so the group ID can't have been reused.
+                        unsafe {
+                            libc::kill(-(pgid as libc::pid_t), libc::SIGKILL);
+                        }
+                    }
+
+                    return Err(anyhow!(
+                        "{} timed out and was killed ({} secs)",
+                        self.program,
+                        self.timeout
+                    ));
+                }
+            };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        // The exit code is missing if the process was killed by a signal.
+        match output.status.code() {
+            Some(code) if self.exit_codes.contains(&code) => (),
+            Some(code) => {
+                return Err(anyhow!(
+                    "{} exited with code {}: {}",
+                    self.program,
+                    code,
+                    Self::truncate(&stderr)
+                ));
+            }
+            None => {
+                return Err(anyhow!(
+                    "{} was terminated by a signal: {}",
+                    self.program,
+                    Self::truncate(&stderr)
+                ));
+            }
+        }
+
+        if let Some(expect) = &self.stdout
+            && !expect.is_match(&stdout)
+        {
+            return Err(anyhow!(
+                "{} stdout did not match '{}': {}",
+                self.program,
+                expect.as_str(),
+                Self::truncate(&stdout)
+            ));
+        }
+
+        if let Some(expect) = &self.stderr
+            && !expect.is_match(&stderr)
+        {
+            return Err(anyhow!(
+                "{} stderr did not match '{}': {}",
+                self.program,
+                expect.as_str(),
+                Self::truncate(&stderr)
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn truncate(output: &str) -> String {
+        let output = output.trim();
+
+        match output.char_indices().nth(OUTPUT_MAX_LEN) {
+            Some((idx, _)) => format!("{}...", &output[..idx]),
+            None => output.to_string(),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use anyhow::{Result, anyhow};
+
+#[derive(Debug, Clone)]
+pub struct FileCheck {
+    pub path: String,
+
+    pub max_age: Option<u64>,
+
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+}
+
+impl FileCheck {
+    pub async fn exec(&self) -> Result<()> {
+        let (path, mtime, size) = self.newest()?;
+
+        if let Some(max_age) = self.max_age {
+            // A modification time in the future counts as brand new.
+            let age = SystemTime::now()
+                .duration_since(mtime)
+                .map(|age| age.as_secs())
+                .unwrap_or(0);
+
+            if age > max_age {
+                return Err(anyhow!(
+                    "{} was last modified {} secs ago (max {} secs)",
+                    path.display(),
+                    age,
+                    max_age
+                ));
+            }
+        }
+
+        if let Some(min_size) = self.min_size
+            && size < min_size
+        {
+            return Err(anyhow!(
+                "{} is {} bytes (min {} bytes)",
+                path.display(),
+                size,
+                min_size
+            ));
+        }
+
+        if let Some(max_size) = self.max_size
+            && size > max_size
+        {
+            return Err(anyhow!(
+                "{} is {} bytes (max {} bytes)",
+                path.display(),
+                size,
+                max_size
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Finds the most recently modified file matching our path.
+    fn newest(&self) -> Result<(PathBuf, SystemTime, u64)> {
+        let paths = match glob::glob(&self.path) {
+            Ok(paths) => paths,
+            Err(e) => return Err(anyhow!("Invalid file pattern {}: {}", self.path, e)),
+        };
+
+        let mut newest: Option<(PathBuf, SystemTime, u64)> = None;
+
+        for path in paths.flatten() {
+            let meta = match fs::metadata(&path) {
+                Ok(meta) => meta,
+                Err(e) => return Err(anyhow!("Failed to stat {}: {}", path.display(), e)),
+            };
+
+            // Directories matched by the pattern aren't what we're watching for.
+            if !meta.is_file() {
+                continue;
+            }
+
+            let mtime = meta.modified()?;
+
+            if newest.as_ref().is_none_or(|(_, cur, _)| mtime > *cur) {
+                newest = Some((path, mtime, meta.len()));
+            }
+        }
+
+        match newest {
+            Some(newest) => Ok(newest),
+            None => Err(anyhow!("No file found matching {}", self.path)),
+        }
+    }
+}
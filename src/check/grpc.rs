@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use http_body_util::BodyExt;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, TE};
+
+use crate::check::CheckRes;
+use crate::helper::Secret;
+use crate::helper::tls::ClientTls;
+
+#[derive(Debug, Clone)]
+pub struct GrpcCheck {
+    pub host: String,
+    pub port: u16,
+
+    pub timeout: u64,
+
+    pub service: String,
+
+    pub tls: bool,
+    pub is_insecure: bool,
+    pub tls_cfg: ClientTls,
+
+    // Values may hold credentials (e.g. an authorization token), so they're kept out of Debug.
+    pub metadata: HashMap<String, Secret>,
+}
+
+impl GrpcCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let url = self.url();
+
+        // gRPC is always HTTP/2, so we don't wait for an upgrade.
+        let mut builder = reqwest::Client::builder().http2_prior_knowledge();
+
+        if self.tls {
+            let mut tls = self.tls_cfg.client_config(self.is_insecure)?;
+
+            // gRPC only runs over HTTP/2.
+            tls.alpn_protocols = vec![b"h2".to_vec()];
+
+            builder = builder.tls_backend_preconfigured(tls);
+        }
+
+        let cl = builder.build()?;
+
+        let mut req = cl
+            .post(&url)
+            .timeout(Duration::from_secs(self.timeout))
+            .header(CONTENT_TYPE, "application/grpc")
+            .header(TE, "trailers")
+            .body(Self::request_body(&self.service));
+
+        for (key, value) in self.metadata.iter() {
+            req = req.header(key, value.expose());
+        }
+
+        let res = match req.send().await {
+            Ok(res) => res,
+            Err(e) if e.is_timeout() => {
+                return Err(anyhow!("gRPC request timed out ({} secs)", self.timeout));
+            }
+            Err(e) => {
+                return Err(anyhow!("gRPC request failed: {:#}", anyhow::Error::from(e)));
+            }
+        };
+
+        if !res.status().is_success() {
+            return Err(anyhow!(
+                "gRPC request failed with HTTP status {}",
+                res.status()
+            ));
+        }
+
+        let headers = res.headers().clone();
+
+        // The gRPC status usually comes in the trailers, so we need the raw body.
+        let body = match http::Response::from(res).into_body().collect().await {
+            Ok(body) => body,
+            Err(e) if e.is_timeout() => {
+                return Err(anyhow!("gRPC request timed out ({} secs)", self.timeout));
+            }
+            Err(e) => return Err(anyhow!("Failed to read gRPC response: {}", e)),
+        };
+
+        let trailers = body.trailers().cloned().unwrap_or_default();
+
+        // Errors without a message are sent as headers only.
+        Self::check_status(&headers)?;
+        Self::check_status(&trailers)?;
+
+        let status = Self::response_status(&body.to_bytes())?;
+
+        let name = match self.service.is_empty() {
+            true => String::from("Server"),
+            false => format!("Service {}", self.service),
+        };
+
+        match status {
+            1 => Ok(CheckRes::default()),
+            0 => Ok(CheckRes {
+                warn: Some(format!("{} health is UNKNOWN", name)),
+                ..Default::default()
+            }),
+            2 => Err(anyhow!("{} is NOT_SERVING", name)),
+            3 => Err(anyhow!(
+                "{} is unknown to the server (SERVICE_UNKNOWN)",
+                name
+            )),
+            status => Err(anyhow!("{} has an invalid health status: {}", name, status)),
+        }
+    }
+
+    fn url(&self) -> String {
+        // IPv6 literals need brackets in a URL (e.g. "[::1]").
+        let host = match self.host.contains(':') && !self.host.starts_with('[') {
+            true => format!("[{}]", self.host),
+            false => self.host.clone(),
+        };
+
+        format!(
+            "{}://{}:{}/grpc.health.v1.Health/Check",
+            match self.tls {
+                true => "https",
+                false => "http",
+            },
+            host,
+            self.port
+        )
+    }
+
+    // A length-prefixed HealthCheckRequest (field 1 is the service name).
+    fn request_body(service: &str) -> Vec<u8> {
+        let mut msg: Vec<u8> = Vec::new();
+
+        if !service.is_empty() {
+            msg.push(0x0a);
+            Self::put_varint(&mut msg, service.len() as u64);
+            msg.extend_from_slice(service.as_bytes());
+        }
+
+        let mut body: Vec<u8> = vec![0];
+
+        body.extend_from_slice(&(msg.len() as u32).to_be_bytes());
+        body.extend_from_slice(&msg);
+
+        body
+    }
+
+    fn check_status(headers: &HeaderMap) -> Result<()> {
+        let Some(status) = headers.get("grpc-status") else {
+            return Ok(());
+        };
+
+        let status = String::from_utf8_lossy(status.as_bytes()).to_string();
+
+        if status == "0" {
+            return Ok(());
+        }
+
+        let msg = headers
+            .get("grpc-message")
+            .map(|msg| Self::decode_message(msg.as_bytes()))
+            .unwrap_or_default();
+
+        Err(anyhow!("gRPC call failed with status {}: {}", status, msg))
+    }
+
+    // Messages are percent-encoded (e.g. "service%20not%20registered").
+    fn decode_message(msg: &[u8]) -> String {
+        let mut decoded: Vec<u8> = Vec::new();
+        let mut i = 0;
+
+        while i < msg.len() {
+            let hex = match msg[i] {
+                b'%' => msg
+                    .get(i + 1..i + 3)
+                    .and_then(|hex| std::str::from_utf8(hex).ok()),
+                _ => None,
+            };
+
+            match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 3;
+                }
+                None => {
+                    decoded.push(msg[i]);
+                    i += 1;
+                }
+            }
+        }
+
+        String::from_utf8_lossy(&decoded).to_string()
+    }
+
+    // Reads the status (field 1) out of a length-prefixed HealthCheckResponse.
+    fn response_status(body: &[u8]) -> Result<u64> {
+        if body.len() < 5 {
+            return Err(anyhow!("gRPC response is missing its message"));
+        }
+
+        if body[0] != 0 {
+            return Err(anyhow!("gRPC response is compressed"));
+        }
+
+        let len = u32::from_be_bytes([body[1], body[2], body[3], body[4]]) as usize;
+
+        let Some(mut msg) = body.get(5..5 + len) else {
+            return Err(anyhow!("gRPC response is truncated"));
+        };
+
+        // A missing status means UNKNOWN (0).
+        let mut status = 0;
+
+        while !msg.is_empty() {
+            let key = Self::take_varint(&mut msg)?;
+
+            match (key >> 3, key & 0x7) {
+                (1, 0) => status = Self::take_varint(&mut msg)?,
+                (_, 0) => {
+                    Self::take_varint(&mut msg)?;
+                }
+                (_, 2) => {
+                    let len = Self::take_varint(&mut msg)? as usize;
+
+                    match msg.get(len..) {
+                        Some(rest) => msg = rest,
+                        None => return Err(anyhow!("gRPC response is truncated")),
+                    }
+                }
+                (_, wire) => return Err(anyhow!("Unexpected wire type {} in gRPC response", wire)),
+            }
+        }
+
+        Ok(status)
+    }
+
+    fn put_varint(buf: &mut Vec<u8>, mut val: u64) {
+        while val >= 0x80 {
+            buf.push((val as u8) | 0x80);
+            val >>= 7;
+        }
+
+        buf.push(val as u8);
+    }
+
+    fn take_varint(buf: &mut &[u8]) -> Result<u64> {
+        let mut val: u64 = 0;
+
+        for shift in (0..64).step_by(7) {
+            let Some((&byte, rest)) = buf.split_first() else {
+                return Err(anyhow!("gRPC response is truncated"));
+            };
+
+            *buf = rest;
+            val |= ((byte & 0x7f) as u64) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(val);
+            }
+        }
+
+        Err(anyhow!("Invalid varint in gRPC response"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Wraps a HealthCheckResponse in the gRPC length prefix.
+    fn frame(msg: &[u8]) -> Vec<u8> {
+        let mut body = vec![0];
+
+        body.extend_from_slice(&(msg.len() as u32).to_be_bytes());
+        body.extend_from_slice(msg);
+
+        body
+    }
+
+    #[test]
+    fn brackets_ipv6_hosts() {
+        let mut check = GrpcCheck {
+            host: String::from("::1"),
+            port: 50051,
+            timeout: 10,
+            service: String::new(),
+            tls: false,
+            is_insecure: false,
+            tls_cfg: ClientTls::default(),
+            metadata: HashMap::new(),
+        };
+
+        assert_eq!(
+            check.url(),
+            "http://[::1]:50051/grpc.health.v1.Health/Check"
+        );
+
+        check.host = String::from("[::1]");
+        check.tls = true;
+
+        assert_eq!(
+            check.url(),
+            "https://[::1]:50051/grpc.health.v1.Health/Check"
+        );
+
+        check.host = String::from("127.0.0.1");
+
+        assert_eq!(
+            check.url(),
+            "https://127.0.0.1:50051/grpc.health.v1.Health/Check"
+        );
+    }
+
+    #[test]
+    fn reads_varints() {
+        let mut buf: &[u8] = &[0x01, 0xac, 0x02, 0xff];
+
+        assert_eq!(GrpcCheck::take_varint(&mut buf).unwrap(), 1);
+        assert_eq!(GrpcCheck::take_varint(&mut buf).unwrap(), 300);
+
+        // The last byte says more follow, but there aren't any.
+        assert!(GrpcCheck::take_varint(&mut buf).is_err());
+
+        // More than 64 bits.
+        let mut buf: &[u8] = &[0xff; 11];
+
+        assert!(GrpcCheck::take_varint(&mut buf).is_err());
+
+        let mut round: Vec<u8> = Vec::new();
+
+        GrpcCheck::put_varint(&mut round, u64::MAX);
+
+        assert_eq!(
+            GrpcCheck::take_varint(&mut round.as_slice()).unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn reads_statuses() {
+        assert_eq!(
+            GrpcCheck::response_status(&frame(&[0x08, 0x01])).unwrap(),
+            1
+        );
+        assert_eq!(
+            GrpcCheck::response_status(&frame(&[0x08, 0x02])).unwrap(),
+            2
+        );
+
+        // A missing status is UNKNOWN.
+        assert_eq!(GrpcCheck::response_status(&frame(&[])).unwrap(), 0);
+
+        // Unknown fields are skipped.
+        let msg = [0x12, 0x03, b'a', b'b', b'c', 0x18, 0x05, 0x08, 0x01];
+
+        assert_eq!(GrpcCheck::response_status(&frame(&msg)).unwrap(), 1);
+    }
+
+    #[test]
+    fn rejects_malformed_responses() {
+        // Too short for the prefix.
+        assert!(GrpcCheck::response_status(&[0, 0, 0]).is_err());
+
+        // Compressed.
+        let mut body = frame(&[0x08, 0x01]);
+        body[0] = 1;
+
+        assert!(GrpcCheck::response_status(&body).is_err());
+
+        // The prefix claims more than was sent.
+        let mut body = frame(&[0x08, 0x01]);
+        body[4] = 10;
+
+        assert!(GrpcCheck::response_status(&body).is_err());
+
+        // A length-delimited field running past the message.
+        assert!(GrpcCheck::response_status(&frame(&[0x12, 0x05, b'a'])).is_err());
+
+        // A fixed64 field (wire type 1) isn't expected.
+        assert!(GrpcCheck::response_status(&frame(&[0x09, 0, 0, 0, 0, 0, 0, 0, 0])).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use reqwest::cookie::{CookieStore, Jar};
+use serde_json::Value;
+
+use crate::check::http::{HttpCheck, HttpRes};
+use crate::helper::{HttpCapture, format_contents, json_escape, percent_encode};
+
+#[derive(Debug, Clone)]
+pub struct HttpFlowStep {
+    pub name: String,
+
+    pub check: HttpCheck,
+
+    // Variable names and where to capture their values from.
+    pub captures: Vec<(String, HttpCapture)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpFlowCheck {
+    pub steps: Vec<HttpFlowStep>,
+}
+
+impl HttpFlowCheck {
+    pub async fn exec(&self) -> Result<()> {
+        // Every run starts without cookies or captured values.
+        let cookies = Arc::new(Jar::default());
+        let mut vars: HashMap<String, String> = HashMap::new();
+
+        for step in self.steps.iter() {
+            let check = match Self::prepare(step, &vars, &cookies) {
+                Ok(check) => check,
+                Err(e) => return Err(anyhow!("Step {} failed: {}", step.name, e)),
+            };
+
+            let res = match check.send().await {
+                Ok(res) => res,
+                Err(e) => return Err(anyhow!("Step {} failed: {}", step.name, e)),
+            };
+
+            for (var, capture) in step.captures.iter() {
+                let Some(val) = Self::capture(&res, capture, &cookies) else {
+                    return Err(anyhow!(
+                        "Step {} failed: unable to capture {} ({})",
+                        step.name,
+                        var,
+                        capture
+                    ));
+                };
+
+                vars.insert(format!("{{{}}}", var), val);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Fills captured values into the step's URL, headers, and body and shares the cookie jar.
+    fn prepare(
+        step: &HttpFlowStep,
+        vars: &HashMap<String, String>,
+        cookies: &Arc<Jar>,
+    ) -> Result<HttpCheck> {
+        let mut check = step.check.clone();
+
+        // Captured values could contain anything, so they're encoded before going into the URL.
+        let url_vars = vars
+            .iter()
+            .map(|(key, val)| (key.clone(), percent_encode(val)))
+            .collect();
+
+        check.url = format_contents(&check.url, url_vars);
+
+        let mut content_type = String::new();
+
+        if let Some(headers) = &mut check.headers {
+            for (key, val) in headers.iter_mut() {
+                // A line break would end the header early, so such values can't be used at all.
+                if let Some((var, _)) = vars
+                    .iter()
+                    .find(|(var, v)| val.contains(var.as_str()) && v.chars().any(char::is_control))
+                {
+                    return Err(anyhow!(
+                        "{} contains control characters and can't be used in header {}",
+                        var,
+                        key
+                    ));
+                }
+
+                *val = format_contents(val, vars.clone());
+
+                if key.eq_ignore_ascii_case("content-type") {
+                    content_type = val.to_lowercase();
+                }
+            }
+        }
+
+        // The body is filled in when it's loaded, so values are escaped for its content type here.
+        check.body_vars = vars
+            .iter()
+            .map(|(key, val)| {
+                let val = match content_type.as_str() {
+                    ct if ct.contains("json") => json_escape(val),
+                    ct if ct.contains("x-www-form-urlencoded") => percent_encode(val),
+                    _ => val.clone(),
+                };
+
+                (key.clone(), val)
+            })
+            .collect();
+
+        check.cookies = Some(cookies.clone());
+
+        Ok(check)
+    }
+
+    fn capture(res: &HttpRes, capture: &HttpCapture, cookies: &Jar) -> Option<String> {
+        match capture {
+            HttpCapture::Json(pointer) => {
+                let doc: Value = serde_json::from_str(&res.body).ok()?;
+
+                match doc.pointer(pointer)? {
+                    Value::String(val) => Some(val.clone()),
+                    val => Some(val.to_string()),
+                }
+            }
+
+            // Use the first group if there is one, otherwise the whole match.
+            HttpCapture::Regex(re) => {
+                let caps = re.captures(&res.body)?;
+
+                caps.get(1)
+                    .or(caps.get(0))
+                    .map(|val| val.as_str().to_string())
+            }
+
+            // Read from the jar so cookies set during redirects are included.
+            HttpCapture::Cookie(name) => {
+                let header = cookies.cookies(&res.url)?;
+
+                String::from_utf8_lossy(header.as_bytes())
+                    .split(';')
+                    .filter_map(|cookie| cookie.trim().split_once('='))
+                    .find(|(key, _)| key == name)
+                    .map(|(_, val)| val.to_string())
+            }
+
+            HttpCapture::Header(name) => res
+                .headers
+                .get(name)
+                .map(|val| String::from_utf8_lossy(val.as_bytes()).to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::HttpCheckConfig;
+    use crate::helper::load_body;
+
+    use super::*;
+
+    fn step(check: serde_json::Value) -> HttpFlowStep {
+        let cfg: HttpCheckConfig = serde_json::from_value(check).unwrap();
+
+        HttpFlowStep {
+            name: String::from("1"),
+            check: HttpCheck::from_config("test", &cfg).unwrap(),
+            captures: Vec::new(),
+        }
+    }
+
+    fn vars(val: &str) -> HashMap<String, String> {
+        HashMap::from([(String::from("{token}"), val.to_string())])
+    }
+
+    fn body(check: &HttpCheck) -> String {
+        load_body(
+            check.body.as_deref().unwrap(),
+            check.body_is_file,
+            &check.service,
+            check.body_vars.clone(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn escapes_json_bodies() {
+        let step = step(serde_json::json!({
+            "headers": { "Content-Type": "application/json" },
+            "body": "{\"token\": \"{token}\", \"service\": \"{service}\"}",
+        }));
+
+        let check =
+            HttpFlowCheck::prepare(&step, &vars("a\"b\\{service}"), &Arc::default()).unwrap();
+        let doc: Value = serde_json::from_str(&body(&check)).unwrap();
+
+        // The captured value is filled in once, so its placeholder-like text is kept.
+        assert_eq!(doc["token"], "a\"b\\{service}");
+        assert_eq!(doc["service"], "test");
+    }
+
+    #[test]
+    fn encodes_form_bodies() {
+        let step = step(serde_json::json!({
+            "headers": { "Content-Type": "application/x-www-form-urlencoded" },
+            "body": "csrf={token}&a=b",
+        }));
+
+        let check = HttpFlowCheck::prepare(&step, &vars("x&a=c"), &Arc::default()).unwrap();
+
+        assert_eq!(body(&check), "csrf=x%26a%3Dc&a=b");
+    }
+
+    #[test]
+    fn rejects_line_breaks_in_headers() {
+        let step = step(serde_json::json!({
+            "headers": { "Authorization": "Bearer {token}" },
+        }));
+
+        assert!(HttpFlowCheck::prepare(&step, &vars("abc\r\nX-Evil: 1"), &Arc::default()).is_err());
+
+        let check = HttpFlowCheck::prepare(&step, &vars("abc"), &Arc::default()).unwrap();
+
+        assert_eq!(check.headers.unwrap()["Authorization"], "Bearer abc");
+    }
+}
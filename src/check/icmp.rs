@@ -0,0 +1,209 @@
+use std::io::ErrorKind;
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use socket2::{Domain, Protocol, Socket, Type};
+
+use tokio::net::{UdpSocket, lookup_host};
+use tokio::time::{sleep, timeout};
+
+use crate::check::CheckRes;
+
+// ICMP echo message types.
+const ICMPV4_ECHO_REQUEST: u8 = 8;
+const ICMPV4_ECHO_REPLY: u8 = 0;
+const ICMPV6_ECHO_REQUEST: u8 = 128;
+const ICMPV6_ECHO_REPLY: u8 = 129;
+
+// The payload we attach to each echo request.
+const ICMP_PAYLOAD: &[u8] = b"service-monitor!";
+
+#[derive(Debug, Clone)]
+pub struct IcmpCheck {
+    pub host: String,
+
+    pub count: u16,
+    pub interval: u64,
+    pub timeout: u64,
+
+    pub max_loss: f64,
+    pub max_rtt_avg: Option<u32>,
+}
+
+impl IcmpCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let target = self.resolve().await?;
+
+        let sock = Self::open(target)?;
+
+        // The port is ignored for ICMP sockets, but connecting filters replies to our target.
+        sock.connect(SocketAddr::new(target, 0)).await?;
+
+        let mut rtts: Vec<Duration> = Vec::new();
+        let mut last_err: Option<String> = None;
+
+        for seq in 0..self.count {
+            if seq > 0 {
+                sleep(Duration::from_millis(self.interval)).await;
+            }
+
+            let pkt = Self::build_echo(target.is_ipv6(), seq);
+
+            let sent = Instant::now();
+
+            if let Err(e) = sock.send(&pkt).await {
+                last_err = Some(e.to_string());
+
+                continue;
+            }
+
+            match timeout(
+                Duration::from_secs(self.timeout),
+                Self::wait_reply(&sock, target.is_ipv6(), seq),
+            )
+            .await
+            {
+                Ok(Ok(_)) => rtts.push(sent.elapsed()),
+                Ok(Err(e)) => last_err = Some(e.to_string()),
+                Err(_) => (),
+            }
+        }
+
+        let count = self.count.max(1) as f64;
+        let loss = (count - rtts.len() as f64) / count * 100.0;
+
+        if rtts.is_empty() {
+            return Err(anyhow!(
+                "No echo replies from {} (100% packet loss){}",
+                target,
+                match last_err {
+                    Some(e) => format!(": {}", e),
+                    None => String::new(),
+                }
+            ));
+        }
+
+        if loss > self.max_loss {
+            return Err(anyhow!(
+                "Packet loss to {} is {:.1}% (max {:.1}%)",
+                target,
+                loss,
+                self.max_loss
+            ));
+        }
+
+        let rtt_avg =
+            rtts.iter().map(|rtt| rtt.as_secs_f64()).sum::<f64>() / rtts.len() as f64 * 1000.0;
+
+        if let Some(max_rtt_avg) = self.max_rtt_avg
+            && rtt_avg > max_rtt_avg as f64
+        {
+            return Err(anyhow!(
+                "Average RTT to {} is {:.2}ms (max {}ms)",
+                target,
+                rtt_avg,
+                max_rtt_avg
+            ));
+        }
+
+        Ok(CheckRes {
+            lats: rtts.iter().map(|rtt| rtt.as_millis() as u32).collect(),
+            ..Default::default()
+        })
+    }
+
+    async fn resolve(&self) -> Result<IpAddr> {
+        if let Ok(ip) = self.host.parse::<IpAddr>() {
+            return Ok(ip);
+        }
+
+        match lookup_host((self.host.as_str(), 0)).await?.next() {
+            Some(addr) => Ok(addr.ip()),
+            None => Err(anyhow!("Unable to resolve {}", self.host)),
+        }
+    }
+
+    fn open(target: IpAddr) -> Result<UdpSocket> {
+        let (domain, proto) = match target {
+            IpAddr::V4(_) => (Domain::IPV4, Protocol::ICMPV4),
+            IpAddr::V6(_) => (Domain::IPV6, Protocol::ICMPV6),
+        };
+
+        // Unprivileged ICMP datagram sockets don't require root, but the group must be allowed.
+        let sock = match Socket::new(domain, Type::DGRAM, Some(proto)) {
+            Ok(sock) => sock,
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+                return Err(anyhow!(
+                    "Unable to create ICMP socket (check net.ipv4.ping_group_range): {}",
+                    e
+                ));
+            }
+            Err(e) => return Err(anyhow!("Unable to create ICMP socket: {}", e)),
+        };
+
+        sock.set_nonblocking(true)?;
+
+        Ok(UdpSocket::from_std(sock.into())?)
+    }
+
+    fn build_echo(is_v6: bool, seq: u16) -> Vec<u8> {
+        let mut pkt: Vec<u8> = Vec::with_capacity(8 + ICMP_PAYLOAD.len());
+
+        // The identifier is overwritten by the kernel with our socket's ID.
+        pkt.push(match is_v6 {
+            true => ICMPV6_ECHO_REQUEST,
+            false => ICMPV4_ECHO_REQUEST,
+        });
+        pkt.push(0);
+        pkt.extend_from_slice(&[0, 0, 0, 0]);
+        pkt.extend_from_slice(&seq.to_be_bytes());
+        pkt.extend_from_slice(ICMP_PAYLOAD);
+
+        // The kernel fills in the ICMPv6 checksum since it covers the IPv6 pseudo-header.
+        if !is_v6 {
+            let sum = Self::checksum(&pkt);
+
+            pkt[2..4].copy_from_slice(&sum.to_be_bytes());
+        }
+
+        pkt
+    }
+
+    async fn wait_reply(sock: &UdpSocket, is_v6: bool, seq: u16) -> Result<()> {
+        let reply_type = match is_v6 {
+            true => ICMPV6_ECHO_REPLY,
+            false => ICMPV4_ECHO_REPLY,
+        };
+
+        let mut buf = [0u8; 1500];
+
+        // Skip anything that isn't the reply to this sequence (e.g. late replies).
+        loop {
+            let n = sock.recv(&mut buf).await?;
+
+            if n >= 8 && buf[0] == reply_type && u16::from_be_bytes([buf[6], buf[7]]) == seq {
+                return Ok(());
+            }
+        }
+    }
+
+    fn checksum(data: &[u8]) -> u16 {
+        let mut sum: u32 = 0;
+
+        for chunk in data.chunks(2) {
+            let word = match chunk.len() {
+                2 => u16::from_be_bytes([chunk[0], chunk[1]]),
+                _ => u16::from_be_bytes([chunk[0], 0]),
+            };
+
+            sum += word as u32;
+        }
+
+        while sum >> 16 != 0 {
+            sum = (sum & 0xffff) + (sum >> 16);
+        }
+
+        !(sum as u16)
+    }
+}
@@ -1,6 +1,39 @@
+mod disk;
+mod dns;
+mod exec;
+mod file;
+mod grpc;
 mod http;
+mod http_flow;
+mod icmp;
 mod model;
+mod mysql;
+mod postgres;
+mod process;
+mod redis;
+mod smtp;
+mod system;
+mod tcp;
+mod tls;
+mod udp;
 
-pub use http::HttpCheck;
+pub use disk::DiskCheck;
+pub use dns::DnsCheck;
+pub use exec::ExecCheck;
+pub use file::FileCheck;
+pub use grpc::GrpcCheck;
+pub use http::{HttpCheck, HttpRes};
+pub use http_flow::{HttpFlowCheck, HttpFlowStep};
+pub use icmp::IcmpCheck;
 pub use model::Check;
+pub use model::CheckRes;
 pub use model::CheckType;
+pub use mysql::{MysqlAssert, MysqlCheck};
+pub use postgres::{PostgresCheck, PostgresTlsMode};
+pub use process::ProcessCheck;
+pub use redis::RedisCheck;
+pub use smtp::SmtpCheck;
+pub use system::SystemCheck;
+pub use tcp::TcpCheck;
+pub use tls::TlsCheck;
+pub use udp::UdpCheck;
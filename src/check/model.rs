@@ -1,4 +1,22 @@
+use crate::check::disk::DiskCheck;
+use crate::check::dns::DnsCheck;
+use crate::check::exec::ExecCheck;
+use crate::check::file::FileCheck;
+use crate::check::grpc::GrpcCheck;
 use crate::check::http::HttpCheck;
+use crate::check::http_flow::HttpFlowCheck;
+use crate::check::icmp::IcmpCheck;
+use crate::check::mysql::MysqlCheck;
+use crate::check::postgres::PostgresCheck;
+use crate::check::process::ProcessCheck;
+use crate::check::redis::RedisCheck;
+use crate::check::smtp::SmtpCheck;
+use crate::check::system::SystemCheck;
+use crate::check::tcp::TcpCheck;
+use crate::check::tls::TlsCheck;
+use crate::check::udp::UdpCheck;
+
+use crate::helper::timing::HttpTimings;
 
 use anyhow::Result;
 
@@ -6,7 +24,23 @@ use std::fmt;
 
 #[derive(Debug, Clone)]
 pub enum CheckType {
-    Http(HttpCheck),
+    Http(Box<HttpCheck>),
+    Tcp(TcpCheck),
+    Udp(UdpCheck),
+    Icmp(IcmpCheck),
+    Dns(DnsCheck),
+    Tls(TlsCheck),
+    Exec(ExecCheck),
+    Process(ProcessCheck),
+    Disk(DiskCheck),
+    System(SystemCheck),
+    File(FileCheck),
+    HttpFlow(HttpFlowCheck),
+    Redis(RedisCheck),
+    Postgres(PostgresCheck),
+    Mysql(MysqlCheck),
+    Smtp(SmtpCheck),
+    Grpc(GrpcCheck),
 }
 
 impl fmt::Display for CheckType {
@@ -15,6 +49,19 @@ impl fmt::Display for CheckType {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct CheckRes {
+    // Latencies (in milliseconds) measured by the check itself.
+    // If empty, the service uses the time it took to run the check instead.
+    pub lats: Vec<u32>,
+
+    // If set, the check passed but the service should be marked as warning.
+    pub warn: Option<String>,
+
+    // A breakdown of the request's phases (HTTP checks only).
+    pub timings: Option<HttpTimings>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Check {
     pub cron: String,
@@ -37,11 +84,31 @@ impl Check {
         }
     }
 
-    pub async fn exec(&self) -> Result<()> {
+    pub async fn exec(&self) -> Result<CheckRes> {
         let check_type = self.check_type.clone();
 
         match check_type {
-            CheckType::Http(http_check) => return http_check.exec().await,
-        };
+            CheckType::Http(http_check) => http_check.exec().await,
+            CheckType::Tcp(tcp_check) => tcp_check.exec().await.map(|_| CheckRes::default()),
+            CheckType::Udp(udp_check) => udp_check.exec().await.map(|_| CheckRes::default()),
+            CheckType::Icmp(icmp_check) => icmp_check.exec().await,
+            CheckType::Dns(dns_check) => dns_check.exec().await,
+            CheckType::Tls(tls_check) => tls_check.exec().await,
+            CheckType::Exec(exec_check) => exec_check.exec().await.map(|_| CheckRes::default()),
+            CheckType::Process(process_check) => {
+                process_check.exec().await.map(|_| CheckRes::default())
+            }
+            CheckType::Disk(disk_check) => disk_check.exec().await,
+            CheckType::System(system_check) => system_check.exec().await,
+            CheckType::File(file_check) => file_check.exec().await.map(|_| CheckRes::default()),
+            CheckType::HttpFlow(http_flow_check) => {
+                http_flow_check.exec().await.map(|_| CheckRes::default())
+            }
+            CheckType::Redis(redis_check) => redis_check.exec().await,
+            CheckType::Postgres(postgres_check) => postgres_check.exec().await,
+            CheckType::Mysql(mysql_check) => mysql_check.exec().await.map(|_| CheckRes::default()),
+            CheckType::Smtp(smtp_check) => smtp_check.exec().await,
+            CheckType::Grpc(grpc_check) => grpc_check.exec().await,
+        }
     }
 }
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use mysql_async::prelude::Queryable;
+use mysql_async::{Conn, OptsBuilder, Row, Value};
+use tokio::time::timeout;
+
+use crate::helper::Secret;
+use crate::helper::cmp::CmpOp;
+
+// An assertion against a column of the first row (e.g. `Slave_IO_Running == Yes`).
+// If there's no operator, the column only has to exist and not be NULL.
+#[derive(Debug, Clone)]
+pub struct MysqlAssert {
+    pub column: String,
+    pub cmp: Option<(CmpOp, String)>,
+}
+
+impl MysqlAssert {
+    pub fn parse(expr: &str) -> Result<MysqlAssert> {
+        let Some((column, cmp)) = CmpOp::split(expr)? else {
+            return Err(anyhow!("Invalid column assertion: {}", expr));
+        };
+
+        if column.is_empty() {
+            return Err(anyhow!("Invalid column assertion: {}", expr));
+        }
+
+        // Quotes are optional around strings.
+        let cmp = cmp.map(|(op, val)| (op, val.trim_matches('"').to_string()));
+
+        Ok(MysqlAssert { column, cmp })
+    }
+
+    // Returns a description of the failure if the row doesn't satisfy the assertion.
+    fn eval(&self, row: &Row) -> Option<String> {
+        // Column names aren't case sensitive in MySQL.
+        let Some(idx) = row
+            .columns_ref()
+            .iter()
+            .position(|col| col.name_str().eq_ignore_ascii_case(&self.column))
+        else {
+            return Some(format!("Column {} not found", self.column));
+        };
+
+        let Some(actual) = row.as_ref(idx).and_then(MysqlCheck::value_str) else {
+            return Some(format!("Column {} is NULL", self.column));
+        };
+
+        let (op, expected) = self.cmp.as_ref()?;
+
+        // Compare as numbers when both sides are numbers so "9" < "10".
+        let ord = match (actual.parse::<f64>(), expected.parse::<f64>()) {
+            (Ok(a), Ok(b)) => a.partial_cmp(&b),
+            _ => Some(actual.as_str().cmp(expected)),
+        };
+
+        let ok = op.matches(ord);
+
+        match ok {
+            true => None,
+            false => Some(format!(
+                "Column {} is '{}' (expected {} {})",
+                self.column, actual, op, expected
+            )),
+        }
+    }
+}
+
+impl fmt::Display for MysqlAssert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cmp {
+            Some((op, val)) => write!(f, "{} {} {}", self.column, op, val),
+            None => write!(f, "{}", self.column),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MysqlCheck {
+    pub host: String,
+    pub port: u16,
+
+    pub timeout: u64,
+
+    pub user: String,
+    pub password: Option<Secret>,
+    pub database: Option<String>,
+
+    pub query: Option<String>,
+    pub expect: Vec<MysqlAssert>,
+}
+
+impl MysqlCheck {
+    pub async fn exec(&self) -> Result<()> {
+        let addr = format!("{}:{}", self.host, self.port);
+
+        // The timeout covers the entire exchange (handshake, auth, and query).
+        match timeout(Duration::from_secs(self.timeout), self.probe(&addr)).await {
+            Ok(res) => res,
+            Err(_) => Err(anyhow!(
+                "MySQL check to {} timed out ({} secs)",
+                addr,
+                self.timeout
+            )),
+        }
+    }
+
+    async fn probe(&self, addr: &str) -> Result<()> {
+        let opts = OptsBuilder::default()
+            .ip_or_hostname(self.host.clone())
+            .tcp_port(self.port)
+            .user(Some(self.user.clone()))
+            .pass(self.password.as_ref().map(|password| password.expose()))
+            .db_name(self.database.clone())
+            .prefer_socket(false);
+
+        let mut conn = match Conn::new(opts).await {
+            Ok(conn) => conn,
+            Err(e) => return Err(anyhow!("MySQL connect to {} failed: {}", addr, e)),
+        };
+
+        let res = self.run(&mut conn).await;
+
+        // We're done either way, so a failed goodbye doesn't matter.
+        let _ = conn.disconnect().await;
+
+        res
+    }
+
+    async fn run(&self, conn: &mut Conn) -> Result<()> {
+        let Some(query) = &self.query else {
+            if let Err(e) = conn.ping().await {
+                return Err(anyhow!("MySQL ping failed: {}", e));
+            }
+
+            return Ok(());
+        };
+
+        let row: Option<Row> = match conn.query_first(query).await {
+            Ok(row) => row,
+            Err(e) => return Err(anyhow!("MySQL query failed: {}", e)),
+        };
+
+        if self.expect.is_empty() {
+            return Ok(());
+        }
+
+        let Some(row) = row else {
+            return Err(anyhow!("Query returned no rows"));
+        };
+
+        let fails: Vec<String> = self
+            .expect
+            .iter()
+            .filter_map(|assert| assert.eval(&row))
+            .collect();
+
+        if !fails.is_empty() {
+            return Err(anyhow!("{}", fails.join("; ")));
+        }
+
+        Ok(())
+    }
+
+    // Queries use the text protocol, so most values come back as bytes.
+    fn value_str(val: &Value) -> Option<String> {
+        match val {
+            Value::NULL => None,
+            Value::Bytes(bytes) => Some(String::from_utf8_lossy(bytes).to_string()),
+            val => Some(val.as_sql(true).trim_matches('\'').to_string()),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use tokio::time::timeout;
+use tokio_postgres::config::SslMode;
+use tokio_postgres::tls::{MakeTlsConnect, NoTls, TlsConnect};
+use tokio_postgres::{Client, Config, SimpleQueryMessage, Socket};
+use tokio_postgres_rustls::MakeRustlsConnect;
+
+use crate::check::CheckRes;
+use crate::helper::Secret;
+use crate::helper::tls::ClientTls;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PostgresTlsMode {
+    Disable,
+    Prefer,
+    Require,
+    VerifyFull,
+}
+
+impl PostgresTlsMode {
+    pub fn from_string(mode: &str) -> Result<PostgresTlsMode> {
+        match mode.to_lowercase().as_str() {
+            "disable" => Ok(PostgresTlsMode::Disable),
+            "prefer" => Ok(PostgresTlsMode::Prefer),
+            "require" => Ok(PostgresTlsMode::Require),
+            "verify-full" => Ok(PostgresTlsMode::VerifyFull),
+            _ => Err(anyhow!("Invalid Postgres TLS mode: {}", mode)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresCheck {
+    pub host: String,
+    pub port: u16,
+
+    pub timeout: u64,
+
+    pub user: String,
+    pub password: Option<Secret>,
+    pub database: Option<String>,
+
+    pub tls_mode: PostgresTlsMode,
+    pub tls: ClientTls,
+
+    pub query: String,
+
+    pub expect: Option<String>,
+
+    pub warn_min: Option<f64>,
+    pub fail_min: Option<f64>,
+
+    pub warn_max: Option<f64>,
+    pub fail_max: Option<f64>,
+}
+
+impl PostgresCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let addr = format!("{}:{}", self.host, self.port);
+
+        // The timeout covers the entire exchange (connect, auth, and query).
+        match timeout(Duration::from_secs(self.timeout), self.probe(&addr)).await {
+            Ok(res) => res,
+            Err(_) => Err(anyhow!(
+                "Postgres check to {} timed out ({} secs)",
+                addr,
+                self.timeout
+            )),
+        }
+    }
+
+    async fn probe(&self, addr: &str) -> Result<CheckRes> {
+        let mut cfg = Config::new();
+
+        cfg.host(&self.host)
+            .port(self.port)
+            .user(&self.user)
+            .dbname(self.database.as_deref().unwrap_or(&self.user))
+            .application_name("service-monitor")
+            .ssl_mode(match self.tls_mode {
+                PostgresTlsMode::Disable => SslMode::Disable,
+                PostgresTlsMode::Prefer => SslMode::Prefer,
+                PostgresTlsMode::Require | PostgresTlsMode::VerifyFull => SslMode::Require,
+            });
+
+        if let Some(password) = &self.password {
+            cfg.password(password.expose());
+        }
+
+        let client = match self.tls_mode {
+            PostgresTlsMode::Disable => Self::connect(&cfg, NoTls).await,
+            _ => {
+                // Only verify-full checks the server's certificate (like libpq).
+                let tls = self
+                    .tls
+                    .client_config(self.tls_mode != PostgresTlsMode::VerifyFull)?;
+
+                Self::connect(&cfg, MakeRustlsConnect::new(tls)).await
+            }
+        };
+
+        let client = match client {
+            Ok(client) => client,
+            // Include the underlying cause (e.g. the server's message) since the error itself is vague.
+            Err(e) => {
+                return Err(anyhow!(
+                    "Postgres connect to {} failed: {:#}",
+                    addr,
+                    anyhow::Error::from(e)
+                ));
+            }
+        };
+
+        // The simple query protocol gives us every value as text, whatever its type.
+        let msgs = match client.simple_query(&self.query).await {
+            Ok(msgs) => msgs,
+            Err(e) => {
+                return Err(anyhow!(
+                    "Postgres query failed: {:#}",
+                    anyhow::Error::from(e)
+                ));
+            }
+        };
+
+        let cell = msgs.iter().find_map(|msg| match msg {
+            SimpleQueryMessage::Row(row) => Some(row.try_get(0).ok().flatten()),
+            _ => None,
+        });
+
+        self.check_cell(cell)
+    }
+
+    async fn connect<T>(cfg: &Config, tls: T) -> Result<Client, tokio_postgres::Error>
+    where
+        T: MakeTlsConnect<Socket> + 'static,
+        T::Stream: Send + 'static,
+        T::TlsConnect: Send,
+        <T::TlsConnect as TlsConnect<Socket>>::Future: Send,
+    {
+        let (client, conn) = cfg.connect(tls).await?;
+
+        // The connection does the actual IO and finishes once the client is dropped.
+        tokio::spawn(conn);
+
+        Ok(client)
+    }
+
+    // The cell is None if the query returned no rows and Some(None) if it was NULL.
+    fn check_cell(&self, cell: Option<Option<&str>>) -> Result<CheckRes> {
+        let has_thresholds = self.warn_min.is_some()
+            || self.fail_min.is_some()
+            || self.warn_max.is_some()
+            || self.fail_max.is_some();
+
+        if self.expect.is_none() && !has_thresholds {
+            return Ok(CheckRes::default());
+        }
+
+        let val = match cell {
+            Some(Some(val)) => val,
+            Some(None) => return Err(anyhow!("Query returned NULL")),
+            None => return Err(anyhow!("Query returned no rows")),
+        };
+
+        if let Some(expect) = &self.expect
+            && val != expect
+        {
+            return Err(anyhow!("Query returned '{}' (expected '{}')", val, expect));
+        }
+
+        if !has_thresholds {
+            return Ok(CheckRes::default());
+        }
+
+        let num: f64 = match val.trim().parse() {
+            Ok(num) => num,
+            Err(_) => return Err(anyhow!("Query returned '{}' which is not a number", val)),
+        };
+
+        if let Some(limit) = self.fail_min
+            && num < limit
+        {
+            return Err(anyhow!("Query returned {} (fail under {})", num, limit));
+        }
+
+        if let Some(limit) = self.fail_max
+            && num >= limit
+        {
+            return Err(anyhow!("Query returned {} (fail at {})", num, limit));
+        }
+
+        let mut res = CheckRes::default();
+
+        if let Some(limit) = self.warn_min
+            && num < limit
+        {
+            res.warn = Some(format!("Query returned {} (warn under {})", num, limit));
+        } else if let Some(limit) = self.warn_max
+            && num >= limit
+        {
+            res.warn = Some(format!("Query returned {} (warn at {})", num, limit));
+        }
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,186 @@
+use std::fs;
+
+use anyhow::{Result, anyhow};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct ProcessCheck {
+    pub pattern: Option<Regex>,
+    pub match_cmdline: bool,
+
+    pub min: u32,
+    pub max: Option<u32>,
+
+    pub max_rss: Option<u64>,
+    pub max_cpu_time: Option<u64>,
+
+    pub pidfile: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct ProcInfo {
+    pid: u32,
+    name: String,
+    cmdline: String,
+
+    // In bytes.
+    rss: u64,
+
+    // In seconds (user + system).
+    cpu_time: f64,
+}
+
+impl ProcessCheck {
+    pub async fn exec(&self) -> Result<()> {
+        let mut procs: Vec<ProcInfo> = Vec::new();
+
+        if let Some(pattern) = &self.pattern {
+            let self_pid = std::process::id();
+
+            for entry in fs::read_dir("/proc")? {
+                // Only numeric entries are processes.
+                let Some(pid) = entry?
+                    .file_name()
+                    .to_str()
+                    .and_then(|name| name.parse::<u32>().ok())
+                else {
+                    continue;
+                };
+
+                if pid == self_pid {
+                    continue;
+                }
+
+                // The process may have exited while we were scanning.
+                let Some(info) = Self::read_proc(pid) else {
+                    continue;
+                };
+
+                let haystack = match self.match_cmdline {
+                    true => &info.cmdline,
+                    false => &info.name,
+                };
+
+                if pattern.is_match(haystack) {
+                    procs.push(info);
+                }
+            }
+
+            let cnt = procs.len() as u32;
+
+            if cnt < self.min || self.max.is_some_and(|max| cnt > max) {
+                return Err(anyhow!(
+                    "Found {} processes matching '{}' (expected {} - {})",
+                    cnt,
+                    pattern.as_str(),
+                    self.min,
+                    match self.max {
+                        Some(max) => max.to_string(),
+                        None => String::from("any"),
+                    }
+                ));
+            }
+        }
+
+        if let Some(pidfile) = &self.pidfile {
+            let pid: u32 = match fs::read_to_string(pidfile) {
+                Ok(contents) => match contents.trim().parse() {
+                    Ok(pid) => pid,
+                    Err(e) => return Err(anyhow!("Invalid PID in {}: {}", pidfile, e)),
+                },
+                Err(e) => return Err(anyhow!("Failed to read pidfile {}: {}", pidfile, e)),
+            };
+
+            let Some(info) = Self::read_proc(pid) else {
+                return Err(anyhow!("Process {} from {} isn't running", pid, pidfile));
+            };
+
+            // If we're matching a pattern, the pidfile's process must be one of them.
+            match &self.pattern {
+                Some(pattern) if !procs.iter().any(|proc| proc.pid == pid) => {
+                    return Err(anyhow!(
+                        "Process {} ({}) from {} doesn't match '{}'",
+                        pid,
+                        info.name,
+                        pidfile,
+                        pattern.as_str()
+                    ));
+                }
+                Some(_) => (),
+                None => procs.push(info),
+            }
+        }
+
+        for proc in procs.iter() {
+            if let Some(max_rss) = self.max_rss
+                && proc.rss > max_rss * 1024 * 1024
+            {
+                return Err(anyhow!(
+                    "Process {} ({}) RSS is {}MB (max {}MB)",
+                    proc.pid,
+                    proc.name,
+                    proc.rss / 1024 / 1024,
+                    max_rss
+                ));
+            }
+
+            if let Some(max_cpu_time) = self.max_cpu_time
+                && proc.cpu_time > max_cpu_time as f64
+            {
+                return Err(anyhow!(
+                    "Process {} ({}) CPU time is {:.0}s (max {}s)",
+                    proc.pid,
+                    proc.name,
+                    proc.cpu_time,
+                    max_cpu_time
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_proc(pid: u32) -> Option<ProcInfo> {
+        let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+
+        // The name is wrapped in parentheses and may contain spaces or parentheses itself.
+        let name_start = stat.find('(')?;
+        let name_end = stat.rfind(')')?;
+
+        let name = stat[name_start + 1..name_end].to_string();
+
+        // Fields after the name start at the state (field 3 in proc(5)).
+        let fields: Vec<&str> = stat[name_end + 1..].split_whitespace().collect();
+
+        let utime: u64 = fields.get(11)?.parse().ok()?;
+        let stime: u64 = fields.get(12)?.parse().ok()?;
+        let rss_pages: u64 = fields.get(21)?.parse().ok()?;
+
+        // Arguments are separated by null bytes.
+        let cmdline = fs::read(format!("/proc/{}/cmdline", pid))
+            .map(|raw| {
+                String::from_utf8_lossy(&raw)
+                    .split('\0')
+                    .filter(|arg| !arg.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .unwrap_or_default();
+
+        // SAFETY: sysconf() only reads system configuration values.
+        let (page_size, clk_tck) = unsafe {
+            (
+                libc::sysconf(libc::_SC_PAGESIZE) as u64,
+                libc::sysconf(libc::_SC_CLK_TCK) as f64,
+            )
+        };
+
+        Some(ProcInfo {
+            pid,
+            name,
+            cmdline,
+            rss: rss_pages * page_size,
+            cpu_time: (utime + stime) as f64 / clk_tck,
+        })
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use crate::check::CheckRes;
+use crate::helper::Secret;
+
+// INFO replies are a few KiB, so anything near this is not a sane reply.
+const MAX_BULK_LEN: usize = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct RedisCheck {
+    pub host: String,
+    pub port: u16,
+
+    pub timeout: u64,
+
+    pub username: Option<String>,
+    pub password: Option<Secret>,
+
+    pub role: Option<String>,
+
+    pub min_replicas: Option<u64>,
+
+    pub warn_used_memory_mb: Option<u64>,
+    pub fail_used_memory_mb: Option<u64>,
+
+    pub warn_blocked_clients: Option<u64>,
+    pub fail_blocked_clients: Option<u64>,
+}
+
+// The RESP reply types we care about.
+#[derive(Debug, Clone)]
+enum Reply {
+    Simple(String),
+    Error(String),
+    Bulk(Option<String>),
+}
+
+impl RedisCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let addr = format!("{}:{}", self.host, self.port);
+
+        // The timeout covers the entire exchange (connect, auth, and commands).
+        match timeout(Duration::from_secs(self.timeout), self.probe(&addr)).await {
+            Ok(res) => res,
+            Err(_) => Err(anyhow!(
+                "Redis check to {} timed out ({} secs)",
+                addr,
+                self.timeout
+            )),
+        }
+    }
+
+    async fn probe(&self, addr: &str) -> Result<CheckRes> {
+        let stream = match TcpStream::connect(addr).await {
+            Ok(stream) => stream,
+            Err(e) => return Err(anyhow!("Redis connect to {} failed: {}", addr, e)),
+        };
+
+        let mut stream = BufReader::new(stream);
+
+        if let Some(password) = &self.password {
+            let mut args = vec!["AUTH"];
+
+            if let Some(username) = &self.username {
+                args.push(username);
+            }
+
+            args.push(password.expose());
+
+            match Self::command(&mut stream, &args).await? {
+                Reply::Simple(_) => (),
+                Reply::Error(e) => return Err(anyhow!("Redis AUTH failed: {}", e)),
+                reply => return Err(anyhow!("Unexpected reply to AUTH: {:?}", reply)),
+            }
+        }
+
+        match Self::command(&mut stream, &["PING"]).await? {
+            Reply::Simple(pong) if pong == "PONG" => (),
+            Reply::Error(e) => return Err(anyhow!("Redis PING failed: {}", e)),
+            reply => return Err(anyhow!("Unexpected reply to PING: {:?}", reply)),
+        }
+
+        // We only need INFO if we have something to assert on.
+        if self.role.is_none()
+            && self.min_replicas.is_none()
+            && self.warn_used_memory_mb.is_none()
+            && self.fail_used_memory_mb.is_none()
+            && self.warn_blocked_clients.is_none()
+            && self.fail_blocked_clients.is_none()
+        {
+            return Ok(CheckRes::default());
+        }
+
+        let info = match Self::command(&mut stream, &["INFO"]).await? {
+            Reply::Bulk(Some(info)) => Self::parse_info(&info),
+            Reply::Error(e) => return Err(anyhow!("Redis INFO failed: {}", e)),
+            reply => return Err(anyhow!("Unexpected reply to INFO: {:?}", reply)),
+        };
+
+        self.check_info(&info)
+    }
+
+    fn check_info(&self, info: &HashMap<String, String>) -> Result<CheckRes> {
+        let mut fails: Vec<String> = Vec::new();
+        let mut warns: Vec<String> = Vec::new();
+
+        // Redis still calls replicas slaves in INFO.
+        let role = match Self::field(info, "role")?.as_str() {
+            "slave" => String::from("replica"),
+            role => role.to_string(),
+        };
+
+        if let Some(expected) = &self.role {
+            let expected = match expected.to_lowercase().as_str() {
+                "slave" => String::from("replica"),
+                expected => expected.to_string(),
+            };
+
+            if role != expected {
+                fails.push(format!("Role is {} (expected {})", role, expected));
+            }
+        }
+
+        // A replica that lost its master still answers PING, so check the link too.
+        if role == "replica" {
+            let link = Self::field(info, "master_link_status")?;
+
+            if link != "up" {
+                fails.push(format!("Master link is {}", link));
+            }
+        }
+
+        if let Some(min) = self.min_replicas {
+            let replicas = Self::num_field(info, "connected_slaves")?;
+
+            if replicas < min {
+                fails.push(format!(
+                    "{} replicas connected (expected at least {})",
+                    replicas, min
+                ));
+            }
+        }
+
+        if self.warn_used_memory_mb.is_some() || self.fail_used_memory_mb.is_some() {
+            let used_mb = Self::num_field(info, "used_memory")? / 1024 / 1024;
+
+            if let Some(mb) = self.fail_used_memory_mb
+                && used_mb >= mb
+            {
+                fails.push(format!("Used memory is {}MB (fail at {}MB)", used_mb, mb));
+            } else if let Some(mb) = self.warn_used_memory_mb
+                && used_mb >= mb
+            {
+                warns.push(format!("Used memory is {}MB (warn at {}MB)", used_mb, mb));
+            }
+        }
+
+        if self.warn_blocked_clients.is_some() || self.fail_blocked_clients.is_some() {
+            let blocked = Self::num_field(info, "blocked_clients")?;
+
+            if let Some(max) = self.fail_blocked_clients
+                && blocked >= max
+            {
+                fails.push(format!("{} clients blocked (fail at {})", blocked, max));
+            } else if let Some(max) = self.warn_blocked_clients
+                && blocked >= max
+            {
+                warns.push(format!("{} clients blocked (warn at {})", blocked, max));
+            }
+        }
+
+        if !fails.is_empty() {
+            return Err(anyhow!("{}", fails.join("; ")));
+        }
+
+        let mut res = CheckRes::default();
+
+        if !warns.is_empty() {
+            res.warn = Some(warns.join("; "));
+        }
+
+        Ok(res)
+    }
+
+    // Sends a command as a RESP array of bulk strings and reads the reply.
+    async fn command(stream: &mut BufReader<TcpStream>, args: &[&str]) -> Result<Reply> {
+        let mut req = format!("*{}\r\n", args.len());
+
+        for arg in args.iter() {
+            req.push_str(&format!("${}\r\n{}\r\n", arg.len(), arg));
+        }
+
+        if let Err(e) = stream.get_mut().write_all(req.as_bytes()).await {
+            return Err(anyhow!("Failed to send {} to Redis: {}", args[0], e));
+        }
+
+        match Self::read_reply(stream).await {
+            Ok(reply) => Ok(reply),
+            Err(e) => Err(anyhow!("Failed to read reply to {}: {}", args[0], e)),
+        }
+    }
+
+    async fn read_reply(stream: &mut BufReader<TcpStream>) -> Result<Reply> {
+        let mut line = String::new();
+
+        if stream.read_line(&mut line).await? == 0 {
+            return Err(anyhow!("connection closed"));
+        }
+
+        let line = line.trim_end_matches("\r\n");
+
+        let Some(kind) = line.chars().next() else {
+            return Err(anyhow!("empty reply"));
+        };
+
+        // The port may not be Redis at all, so the first character could be multi-byte.
+        let val = &line[kind.len_utf8()..];
+
+        match kind {
+            // None of our commands need integers as numbers, so they're kept as text.
+            '+' | ':' => Ok(Reply::Simple(val.to_string())),
+            '-' => Ok(Reply::Error(val.to_string())),
+            '$' => {
+                // A length of -1 is a nil reply.
+                let Ok(len) = usize::try_from(val.parse::<i64>()?) else {
+                    return Ok(Reply::Bulk(None));
+                };
+
+                // The length comes from the server, so don't trust it with an allocation.
+                if len > MAX_BULK_LEN {
+                    return Err(anyhow!("bulk reply too large ({} bytes)", len));
+                }
+
+                // Read the trailing CRLF too.
+                let mut buf = vec![0u8; len + 2];
+
+                stream.read_exact(&mut buf).await?;
+                buf.truncate(len);
+
+                Ok(Reply::Bulk(Some(String::from_utf8_lossy(&buf).to_string())))
+            }
+            _ => Err(anyhow!("unexpected reply: {}", line)),
+        }
+    }
+
+    // INFO is a list of `key:value` lines split into sections by `# Name` comments.
+    fn parse_info(info: &str) -> HashMap<String, String> {
+        info.lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.trim().split_once(':'))
+            .map(|(key, val)| (key.to_string(), val.to_string()))
+            .collect()
+    }
+
+    fn field(info: &HashMap<String, String>, key: &str) -> Result<String> {
+        match info.get(key) {
+            Some(val) => Ok(val.clone()),
+            None => Err(anyhow!("Redis INFO is missing {}", key)),
+        }
+    }
+
+    fn num_field(info: &HashMap<String, String>, key: &str) -> Result<u64> {
+        let val = Self::field(info, key)?;
+
+        match val.parse() {
+            Ok(num) => Ok(num),
+            Err(_) => Err(anyhow!("Redis INFO {} is not a number: {}", key, val)),
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use base64::prelude::{BASE64_STANDARD, Engine};
+
+use rustls::pki_types::ServerName;
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::TlsConnector;
+
+use crate::check::CheckRes;
+use crate::helper::Secret;
+use crate::helper::tls::{RecordingVerifier, cert_info, check_cert, client_config, load_roots};
+
+#[derive(Debug, Clone)]
+pub struct SmtpCheck {
+    pub host: String,
+    pub port: u16,
+
+    pub timeout: u64,
+
+    pub ehlo_name: String,
+
+    pub expect_extensions: Vec<String>,
+
+    pub starttls: bool,
+    pub sni: Option<String>,
+
+    pub is_insecure: bool,
+    pub ca_file: Option<String>,
+
+    pub warn_days: i64,
+    pub fail_days: i64,
+
+    pub username: Option<String>,
+    pub password: Option<Secret>,
+
+    pub allow_plaintext_auth: bool,
+}
+
+impl SmtpCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let addr = format!("{}:{}", self.host, self.port);
+
+        // The timeout covers the entire session, so a server stalling at any stage fails.
+        match timeout(Duration::from_secs(self.timeout), self.probe(&addr)).await {
+            Ok(res) => res,
+            Err(_) => Err(anyhow!(
+                "SMTP check to {} timed out ({} secs)",
+                addr,
+                self.timeout
+            )),
+        }
+    }
+
+    async fn probe(&self, addr: &str) -> Result<CheckRes> {
+        let stream = match TcpStream::connect(addr).await {
+            Ok(stream) => stream,
+            Err(e) => return Err(anyhow!("SMTP connect to {} failed: {}", addr, e)),
+        };
+
+        let mut stream = BufReader::new(stream);
+
+        Self::read_reply(&mut stream, "banner", 220).await?;
+
+        let exts = self.ehlo(&mut stream).await?;
+
+        if !self.starttls {
+            self.session(&mut stream, &exts).await?;
+
+            return Ok(CheckRes::default());
+        }
+
+        if !Self::has_ext(&exts, "STARTTLS") {
+            return Err(anyhow!("Server doesn't advertise STARTTLS"));
+        }
+
+        Self::command(&mut stream, "STARTTLS", "STARTTLS", 220).await?;
+
+        // The name we send as the SNI and expect the certificate to match.
+        let name = self.sni.clone().unwrap_or(self.host.clone());
+
+        let verifier = RecordingVerifier::new(load_roots(self.ca_file.as_deref())?)?;
+        let connector = TlsConnector::from(Arc::new(client_config(verifier.clone())?));
+
+        let server_name = match ServerName::try_from(name.clone()) {
+            Ok(server_name) => server_name,
+            Err(e) => return Err(anyhow!("Invalid TLS server name {}: {}", name, e)),
+        };
+
+        // The server shouldn't send anything until we start the handshake, so nothing is buffered.
+        let stream = match connector.connect(server_name, stream.into_inner()).await {
+            Ok(stream) => stream,
+            Err(e) => return Err(anyhow!("STARTTLS handshake with {} failed: {}", addr, e)),
+        };
+
+        let info = match stream
+            .get_ref()
+            .1
+            .peer_certificates()
+            .and_then(|certs| certs.first())
+        {
+            Some(leaf) => cert_info(leaf)?,
+            None => return Err(anyhow!("{} didn't present a certificate", addr)),
+        };
+
+        check_cert(&info, &name, &verifier, self.is_insecure, self.fail_days)?;
+
+        // We have to introduce ourselves again after upgrading.
+        let mut stream = BufReader::new(stream);
+
+        let exts = self.ehlo(&mut stream).await?;
+
+        self.session(&mut stream, &exts).await?;
+
+        let mut res = CheckRes::default();
+
+        if info.days_left <= self.warn_days {
+            res.warn = Some(format!(
+                "Certificate for {} expires in {} days on {}",
+                name, info.days_left, info.not_after
+            ));
+        }
+
+        Ok(res)
+    }
+
+    // Checks the extensions, authenticates if needed, and says goodbye.
+    async fn session<S>(&self, stream: &mut BufReader<S>, exts: &[String]) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let missing: Vec<&String> = self
+            .expect_extensions
+            .iter()
+            // STARTTLS isn't advertised again after upgrading, but we've already used it.
+            .filter(|ext| !(self.starttls && ext.eq_ignore_ascii_case("STARTTLS")))
+            .filter(|ext| !Self::has_ext(exts, ext))
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(anyhow!(
+                "Server doesn't advertise {} (has {})",
+                missing
+                    .iter()
+                    .map(|ext| ext.to_uppercase())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                exts.join(", ")
+            ));
+        }
+
+        if let Some(username) = &self.username {
+            self.auth(stream, exts, username).await?;
+        }
+
+        Self::command(stream, "QUIT", "QUIT", 221).await?;
+
+        Ok(())
+    }
+
+    async fn auth<S>(
+        &self,
+        stream: &mut BufReader<S>,
+        exts: &[String],
+        username: &str,
+    ) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        // Without STARTTLS the credentials would cross the network in the clear.
+        if !self.starttls && !self.allow_plaintext_auth {
+            return Err(anyhow!(
+                "Refusing to AUTH without STARTTLS (set allow_plaintext_auth to allow it)"
+            ));
+        }
+
+        let password = self
+            .password
+            .as_ref()
+            .map(|p| p.expose())
+            .unwrap_or_default();
+
+        // The AUTH line lists the supported mechanisms (e.g. "AUTH PLAIN LOGIN").
+        // Some older servers also (or only) send the pre-standard "AUTH=PLAIN LOGIN".
+        let mechs: Vec<String> = exts
+            .iter()
+            .filter_map(|ext| ext.strip_prefix("AUTH ").or(ext.strip_prefix("AUTH=")))
+            .flat_map(|mechs| mechs.split_whitespace().map(|mech| mech.to_uppercase()))
+            .collect();
+
+        if mechs.iter().any(|mech| mech == "PLAIN") {
+            let creds = BASE64_STANDARD.encode(format!("\0{}\0{}", username, password));
+
+            return Self::command(stream, &format!("AUTH PLAIN {}", creds), "AUTH", 235).await;
+        }
+
+        if mechs.iter().any(|mech| mech == "LOGIN") {
+            Self::command(stream, "AUTH LOGIN", "AUTH", 334).await?;
+            Self::command(stream, &BASE64_STANDARD.encode(username), "AUTH", 334).await?;
+
+            return Self::command(stream, &BASE64_STANDARD.encode(password), "AUTH", 235).await;
+        }
+
+        Err(anyhow!("Server doesn't support AUTH PLAIN or LOGIN"))
+    }
+
+    // Sends EHLO and returns the advertised extensions (the first line is the greeting).
+    async fn ehlo<S>(&self, stream: &mut BufReader<S>) -> Result<Vec<String>>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        Self::send(stream, &format!("EHLO {}", self.ehlo_name), "EHLO").await?;
+
+        let lines = Self::read_reply(stream, "EHLO", 250).await?;
+
+        Ok(lines
+            .iter()
+            .skip(1)
+            .map(|line| line.trim().to_uppercase())
+            .collect())
+    }
+
+    fn has_ext(exts: &[String], name: &str) -> bool {
+        let name = name.to_uppercase();
+
+        exts.iter()
+            .any(|ext| ext.split_whitespace().next() == Some(name.as_str()))
+    }
+
+    // The stage is used in errors instead of the command so credentials aren't logged.
+    async fn command<S>(
+        stream: &mut BufReader<S>,
+        cmd: &str,
+        stage: &str,
+        expect: u16,
+    ) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        Self::send(stream, cmd, stage).await?;
+        Self::read_reply(stream, stage, expect).await?;
+
+        Ok(())
+    }
+
+    async fn send<S>(stream: &mut BufReader<S>, cmd: &str, stage: &str) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let stream = stream.get_mut();
+
+        if let Err(e) = stream.write_all(format!("{}\r\n", cmd).as_bytes()).await {
+            return Err(anyhow!("Failed to send {}: {}", stage, e));
+        }
+
+        if let Err(e) = stream.flush().await {
+            return Err(anyhow!("Failed to send {}: {}", stage, e));
+        }
+
+        Ok(())
+    }
+
+    // Reads a (possibly multi-line) reply and returns its lines without the codes.
+    async fn read_reply<S>(
+        stream: &mut BufReader<S>,
+        stage: &str,
+        expect: u16,
+    ) -> Result<Vec<String>>
+    where
+        S: AsyncRead + Unpin,
+    {
+        let mut lines: Vec<String> = Vec::new();
+
+        loop {
+            let mut line = String::new();
+
+            match stream.read_line(&mut line).await {
+                Ok(0) => return Err(anyhow!("Connection closed while waiting for {}", stage)),
+                Ok(_) => (),
+                Err(e) => return Err(anyhow!("Failed to read {} reply: {}", stage, e)),
+            }
+
+            let line = line.trim_end();
+
+            let code: u16 = match line.get(..3).map(|code| code.parse()) {
+                Some(Ok(code)) => code,
+                _ => return Err(anyhow!("Invalid {} reply: {}", stage, line)),
+            };
+
+            // A dash after the code means more lines follow.
+            let more = line.get(3..4) == Some("-");
+
+            lines.push(line.get(4..).unwrap_or_default().to_string());
+
+            if more {
+                continue;
+            }
+
+            if code != expect {
+                return Err(anyhow!(
+                    "SMTP {} failed: {} {}",
+                    stage,
+                    code,
+                    lines.join(" ")
+                ));
+            }
+
+            return Ok(lines);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, duplex};
+
+    use super::*;
+
+    async fn reply(data: &str, expect: u16) -> Result<Vec<String>> {
+        SmtpCheck::read_reply(&mut BufReader::new(data.as_bytes()), "TEST", expect).await
+    }
+
+    fn check() -> SmtpCheck {
+        SmtpCheck {
+            host: String::from("127.0.0.1"),
+            port: 25,
+            timeout: 10,
+            ehlo_name: String::from("localhost"),
+            expect_extensions: Vec::new(),
+            starttls: false,
+            sni: None,
+            is_insecure: false,
+            ca_file: None,
+            warn_days: 0,
+            fail_days: 0,
+            username: Some(String::from("user")),
+            password: Some(Secret::from(String::from("pass"))),
+            allow_plaintext_auth: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn reads_replies() {
+        assert_eq!(reply("220 ready\r\n", 220).await.unwrap(), ["ready"]);
+
+        let lines = reply(
+            "250-mail.example.com\r\n250-SIZE 1000\r\n250 AUTH=LOGIN\r\n",
+            250,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(lines, ["mail.example.com", "SIZE 1000", "AUTH=LOGIN"]);
+
+        // A bare code has no text.
+        assert_eq!(reply("250\r\n", 250).await.unwrap(), [""]);
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_replies() {
+        let err = reply("554 no service\r\n", 220).await.unwrap_err();
+
+        assert_eq!(err.to_string(), "SMTP TEST failed: 554 no service");
+
+        // The code is checked once the last line arrives.
+        assert!(reply("250-first\r\n535 denied\r\n", 250).await.is_err());
+
+        assert!(reply("hello\r\n", 220).await.is_err());
+        assert!(reply("22\r\n", 220).await.is_err());
+        assert!(reply("", 220).await.is_err());
+
+        // The server hung up in the middle of a multi-line reply.
+        assert!(reply("250-first\r\n", 250).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn authenticates_with_legacy_auth_line() {
+        let (client, mut server) = duplex(1024);
+
+        // Replies to AUTH LOGIN, the username, and the password.
+        server
+            .write_all(b"334 VXNlcm5hbWU6\r\n334 UGFzc3dvcmQ6\r\n235 ok\r\n")
+            .await
+            .unwrap();
+
+        let exts = [String::from("AUTH=LOGIN")];
+
+        check()
+            .auth(&mut BufReader::new(client), &exts, "user")
+            .await
+            .unwrap();
+
+        let mut sent = String::new();
+
+        server.read_to_string(&mut sent).await.unwrap();
+
+        assert_eq!(sent, "AUTH LOGIN\r\ndXNlcg==\r\ncGFzcw==\r\n");
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::fs;
+use std::thread::available_parallelism;
+
+use anyhow::{Result, anyhow};
+
+use crate::check::CheckRes;
+
+#[derive(Debug, Clone)]
+pub struct SystemCheck {
+    pub load_avg: u8,
+
+    pub warn_load_per_core: Option<f64>,
+    pub fail_load_per_core: Option<f64>,
+
+    pub warn_mem_avail_pct: Option<f64>,
+    pub fail_mem_avail_pct: Option<f64>,
+
+    pub warn_swap_used_pct: Option<f64>,
+    pub fail_swap_used_pct: Option<f64>,
+
+    pub pressure_avg: u16,
+
+    pub warn_cpu_pressure: Option<f64>,
+    pub fail_cpu_pressure: Option<f64>,
+
+    pub warn_mem_pressure: Option<f64>,
+    pub fail_mem_pressure: Option<f64>,
+
+    pub warn_io_pressure: Option<f64>,
+    pub fail_io_pressure: Option<f64>,
+}
+
+// Collects threshold results so we can report everything that's wrong at once.
+#[derive(Debug, Default)]
+struct Results {
+    fails: Vec<String>,
+    warns: Vec<String>,
+}
+
+impl Results {
+    // If below is true, the thresholds are minimums instead of maximums.
+    fn eval(&mut self, desc: &str, val: f64, warn: Option<f64>, fail: Option<f64>, below: bool) {
+        let breached = |limit: f64| match below {
+            true => val < limit,
+            false => val >= limit,
+        };
+
+        if let Some(limit) = fail
+            && breached(limit)
+        {
+            self.fails
+                .push(format!("{} is {:.2} (fail at {})", desc, val, limit));
+        } else if let Some(limit) = warn
+            && breached(limit)
+        {
+            self.warns
+                .push(format!("{} is {:.2} (warn at {})", desc, val, limit));
+        }
+    }
+}
+
+impl SystemCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let mut res = Results::default();
+
+        if self.warn_load_per_core.is_some() || self.fail_load_per_core.is_some() {
+            let load = Self::read_load(self.load_avg)?;
+            let cores = available_parallelism().map(|n| n.get()).unwrap_or(1) as f64;
+
+            res.eval(
+                format!("{}m load per core", self.load_avg).as_str(),
+                load / cores,
+                self.warn_load_per_core,
+                self.fail_load_per_core,
+                false,
+            );
+        }
+
+        let check_mem = self.warn_mem_avail_pct.is_some() || self.fail_mem_avail_pct.is_some();
+        let check_swap = self.warn_swap_used_pct.is_some() || self.fail_swap_used_pct.is_some();
+
+        if check_mem || check_swap {
+            let meminfo = Self::read_meminfo()?;
+
+            let get = |key: &str| match meminfo.get(key) {
+                Some(val) => Ok(*val as f64),
+                None => Err(anyhow!("{} is missing from /proc/meminfo", key)),
+            };
+
+            if check_mem {
+                res.eval(
+                    "Available memory %",
+                    get("MemAvailable")? / get("MemTotal")? * 100.0,
+                    self.warn_mem_avail_pct,
+                    self.fail_mem_avail_pct,
+                    true,
+                );
+            }
+
+            // There's nothing to check if swap is disabled.
+            let swap_total = get("SwapTotal")?;
+
+            if check_swap && swap_total > 0.0 {
+                res.eval(
+                    "Swap used %",
+                    (swap_total - get("SwapFree")?) / swap_total * 100.0,
+                    self.warn_swap_used_pct,
+                    self.fail_swap_used_pct,
+                    false,
+                );
+            }
+        }
+
+        for (resource, warn, fail) in [
+            ("cpu", self.warn_cpu_pressure, self.fail_cpu_pressure),
+            ("memory", self.warn_mem_pressure, self.fail_mem_pressure),
+            ("io", self.warn_io_pressure, self.fail_io_pressure),
+        ] {
+            if warn.is_none() && fail.is_none() {
+                continue;
+            }
+
+            res.eval(
+                format!("{} pressure (some avg{})", resource, self.pressure_avg).as_str(),
+                Self::read_pressure(resource, self.pressure_avg)?,
+                warn,
+                fail,
+                false,
+            );
+        }
+
+        if !res.fails.is_empty() {
+            return Err(anyhow!("{}", res.fails.join("; ")));
+        }
+
+        let mut check_res = CheckRes::default();
+
+        if !res.warns.is_empty() {
+            check_res.warn = Some(res.warns.join("; "));
+        }
+
+        Ok(check_res)
+    }
+
+    // Periods are checked when the config is loaded so a typo fails at startup.
+    pub fn parse_load_avg(period: u8) -> Result<u8> {
+        match period {
+            1 | 5 | 15 => Ok(period),
+            _ => Err(anyhow!(
+                "Invalid load average period: {} (expected 1, 5, or 15)",
+                period
+            )),
+        }
+    }
+
+    pub fn parse_pressure_avg(period: u16) -> Result<u16> {
+        match period {
+            10 | 60 | 300 => Ok(period),
+            _ => Err(anyhow!(
+                "Invalid pressure average period: {} (expected 10, 60, or 300)",
+                period
+            )),
+        }
+    }
+
+    fn read_load(period: u8) -> Result<f64> {
+        let contents = fs::read_to_string("/proc/loadavg")?;
+        let fields: Vec<&str> = contents.split_whitespace().collect();
+
+        let idx = match period {
+            1 => 0,
+            5 => 1,
+            15 => 2,
+            _ => return Err(anyhow!("Invalid load average period: {}", period)),
+        };
+
+        match fields.get(idx) {
+            Some(load) => Ok(load.parse()?),
+            None => Err(anyhow!("Unable to parse /proc/loadavg: {}", contents)),
+        }
+    }
+
+    // Returns each /proc/meminfo value (in kB) by key.
+    fn read_meminfo() -> Result<HashMap<String, u64>> {
+        let contents = fs::read_to_string("/proc/meminfo")?;
+
+        Ok(contents
+            .lines()
+            .filter_map(|line| {
+                let (key, val) = line.split_once(':')?;
+                let val = val.split_whitespace().next()?.parse().ok()?;
+
+                Some((key.to_string(), val))
+            })
+            .collect())
+    }
+
+    // Returns the "some" stall percentage from /proc/pressure/<resource>.
+    fn read_pressure(resource: &str, period: u16) -> Result<f64> {
+        let path = format!("/proc/pressure/{}", resource);
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) => return Err(anyhow!("PSI isn't available ({}): {}", path, e)),
+        };
+
+        let key = format!("avg{}=", period);
+
+        contents
+            .lines()
+            .find(|line| line.starts_with("some "))
+            .and_then(|line| line.split_whitespace().find_map(|f| f.strip_prefix(&key)))
+            .and_then(|val| val.parse().ok())
+            .ok_or_else(|| anyhow!("Unable to find {} in {}", key, path))
+    }
+}
@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use regex::Regex;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+#[derive(Debug, Clone)]
+pub struct TcpCheck {
+    pub host: String,
+    pub port: u16,
+
+    pub timeout: u64,
+
+    pub send: Option<String>,
+    pub expect: Option<Regex>,
+
+    pub read_max: usize,
+}
+
+impl TcpCheck {
+    pub async fn exec(&self) -> Result<()> {
+        let addr = format!("{}:{}", self.host, self.port);
+
+        // The timeout covers the entire exchange (connect, send, and reply).
+        match timeout(Duration::from_secs(self.timeout), self.probe(&addr)).await {
+            Ok(res) => res,
+            Err(_) => Err(anyhow!(
+                "TCP check to {} timed out ({} secs)",
+                addr,
+                self.timeout
+            )),
+        }
+    }
+
+    async fn probe(&self, addr: &str) -> Result<()> {
+        let mut stream = match TcpStream::connect(addr).await {
+            Ok(stream) => stream,
+            Err(e) => return Err(anyhow!("TCP connect to {} failed: {}", addr, e)),
+        };
+
+        // Write our payload if we have one.
+        if let Some(send) = &self.send
+            && let Err(e) = stream.write_all(send.as_bytes()).await
+        {
+            return Err(anyhow!("Failed to send payload to {}: {}", addr, e));
+        }
+
+        // If we don't expect anything back, connecting is enough.
+        let Some(expect) = &self.expect else {
+            return Ok(());
+        };
+
+        // Keep reading until the reply matches, the peer closes, or we hit our read limit.
+        let mut reply: Vec<u8> = Vec::new();
+        let mut buf = [0u8; 1024];
+
+        loop {
+            let n = match stream.read(&mut buf).await {
+                Ok(n) => n,
+                Err(e) => return Err(anyhow!("Failed to read reply from {}: {}", addr, e)),
+            };
+
+            reply.extend_from_slice(&buf[..n]);
+
+            if expect.is_match(&String::from_utf8_lossy(&reply)) {
+                return Ok(());
+            }
+
+            if n == 0 || reply.len() >= self.read_max {
+                break;
+            }
+        }
+
+        Err(anyhow!(
+            "Reply from {} did not match '{}': {:?}",
+            addr,
+            expect.as_str(),
+            String::from_utf8_lossy(&reply)
+        ))
+    }
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+
+use rustls::pki_types::ServerName;
+
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::TlsConnector;
+
+use crate::check::CheckRes;
+use crate::helper::tls::{
+    CertInfo, RecordingVerifier, cert_info, check_cert, client_config, load_roots, san_matches,
+};
+
+#[derive(Debug, Clone)]
+pub struct TlsCheck {
+    pub host: String,
+    pub port: u16,
+    pub sni: Option<String>,
+
+    pub timeout: u64,
+
+    pub is_insecure: bool,
+    pub ca_file: Option<String>,
+
+    pub warn_days: i64,
+    pub fail_days: i64,
+
+    pub sans: Option<Vec<String>>,
+}
+
+impl TlsCheck {
+    pub async fn exec(&self) -> Result<CheckRes> {
+        let addr = format!("{}:{}", self.host, self.port);
+
+        // The name we send as the SNI and expect the certificate to match.
+        let name = self.sni.clone().unwrap_or(self.host.clone());
+
+        let verifier = RecordingVerifier::new(load_roots(self.ca_file.as_deref())?)?;
+
+        let info = match timeout(
+            Duration::from_secs(self.timeout),
+            self.handshake(&addr, &name, verifier.clone()),
+        )
+        .await
+        {
+            Ok(res) => res?,
+            Err(_) => {
+                return Err(anyhow!(
+                    "TLS handshake with {} timed out ({} secs)",
+                    addr,
+                    self.timeout
+                ));
+            }
+        };
+
+        check_cert(&info, &name, &verifier, self.is_insecure, self.fail_days)?;
+
+        if let Some(sans) = &self.sans {
+            for expected in sans {
+                if !info.sans.iter().any(|san| san_matches(san, expected)) {
+                    return Err(anyhow!(
+                        "Certificate for {} doesn't cover {} (SANs: {})",
+                        name,
+                        expected,
+                        info.sans.join(", ")
+                    ));
+                }
+            }
+        }
+
+        let mut res = CheckRes::default();
+
+        if info.days_left <= self.warn_days {
+            res.warn = Some(format!(
+                "Certificate for {} expires in {} days on {}",
+                name, info.days_left, info.not_after
+            ));
+        }
+
+        Ok(res)
+    }
+
+    async fn handshake(
+        &self,
+        addr: &str,
+        name: &str,
+        verifier: Arc<RecordingVerifier>,
+    ) -> Result<CertInfo> {
+        let connector = TlsConnector::from(Arc::new(client_config(verifier)?));
+
+        let server_name = match ServerName::try_from(name.to_string()) {
+            Ok(server_name) => server_name,
+            Err(e) => return Err(anyhow!("Invalid TLS server name {}: {}", name, e)),
+        };
+
+        let stream = match TcpStream::connect(addr).await {
+            Ok(stream) => stream,
+            Err(e) => return Err(anyhow!("TCP connect to {} failed: {}", addr, e)),
+        };
+
+        let stream = match connector.connect(server_name, stream).await {
+            Ok(stream) => stream,
+            Err(e) => return Err(anyhow!("TLS handshake with {} failed: {}", addr, e)),
+        };
+
+        let (_, conn) = stream.get_ref();
+
+        match conn.peer_certificates().and_then(|certs| certs.first()) {
+            Some(leaf) => cert_info(leaf),
+            None => Err(anyhow!("{} didn't present a certificate", addr)),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use regex::bytes::Regex;
+
+use tokio::io::Interest;
+use tokio::net::{UdpSocket, lookup_host};
+use tokio::time::timeout;
+
+#[derive(Debug, Clone)]
+pub struct UdpCheck {
+    pub host: String,
+    pub port: u16,
+
+    pub timeout: u64,
+
+    pub send: Vec<u8>,
+    pub expect: Option<Regex>,
+}
+
+impl UdpCheck {
+    pub async fn exec(&self) -> Result<()> {
+        let addr = format!("{}:{}", self.host, self.port);
+
+        // The timeout covers the entire exchange (resolve, send, and reply).
+        match timeout(Duration::from_secs(self.timeout), self.probe(&addr)).await {
+            Ok(res) => res,
+            Err(_) => Err(anyhow!(
+                "UDP check to {} timed out waiting for a reply ({} secs)",
+                addr,
+                self.timeout
+            )),
+        }
+    }
+
+    async fn probe(&self, addr: &str) -> Result<()> {
+        let target: SocketAddr = match lookup_host(addr).await?.next() {
+            Some(target) => target,
+            None => return Err(anyhow!("Unable to resolve {}", addr)),
+        };
+
+        // We need to bind to the same address family as the target.
+        let bind_addr = match target {
+            SocketAddr::V4(_) => "0.0.0.0:0",
+            SocketAddr::V6(_) => "[::]:0",
+        };
+
+        let sock = UdpSocket::bind(bind_addr).await?;
+
+        // Connecting the socket lets the kernel surface ICMP port-unreachable as an error.
+        sock.connect(target).await?;
+
+        if let Err(e) = sock.send(&self.send).await {
+            return Err(Self::map_err(addr, "send", e));
+        }
+
+        let mut buf = vec![0u8; 65535];
+
+        let n = match Self::recv(&sock, &mut buf).await {
+            Ok(n) => n,
+            Err(e) => return Err(Self::map_err(addr, "receive", e)),
+        };
+
+        let reply = &buf[..n];
+
+        if let Some(expect) = &self.expect
+            && !expect.is_match(reply)
+        {
+            return Err(anyhow!(
+                "Reply from {} did not match '{}': {:?}",
+                addr,
+                expect.as_str(),
+                String::from_utf8_lossy(reply)
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn recv(sock: &UdpSocket, buf: &mut [u8]) -> std::io::Result<usize> {
+        // We wait on error readiness too since ICMP errors don't wake plain readable waiters.
+        let ready = sock.ready(Interest::READABLE | Interest::ERROR).await?;
+
+        if ready.is_error()
+            && let Some(e) = sock.take_error()?
+        {
+            return Err(e);
+        }
+
+        // Fall back to a regular receive so we never spin on stale error readiness.
+        match sock.try_recv(buf) {
+            Err(e) if e.kind() == ErrorKind::WouldBlock => sock.recv(buf).await,
+            res => res,
+        }
+    }
+
+    fn map_err(addr: &str, stage: &str, e: std::io::Error) -> anyhow::Error {
+        if e.kind() == ErrorKind::ConnectionRefused {
+            anyhow!("UDP port unreachable on {} (ICMP port-unreachable)", addr)
+        } else {
+            anyhow!("Failed to {} UDP datagram for {}: {}", stage, addr, e)
+        }
+    }
+}
@@ -3,9 +3,10 @@ use crate::cli::input::UserInput;
 
 impl UserInput {
     pub async fn list_services(&self) {
-        let services = self.services.lock().unwrap();
+        // Clone the services out so we don't hold the lock across awaits.
+        let services = self.services.lock().unwrap().clone();
 
-        if services.len() < 1 {
+        if services.is_empty() {
             println!("No services found...");
 
             return;
@@ -16,7 +17,7 @@ impl UserInput {
         for service in services.iter() {
             println!("\t{}", service.name);
 
-            println!("\t\tStatus => {}", service.get_status().await.to_string());
+            println!("\t\tStatus => {}", service.get_status().await);
 
             println!(
                 "\t\tLatency Min => {}ms",
@@ -35,9 +36,13 @@ impl UserInput {
                 service.lat_last().await.unwrap_or(0)
             );
 
+            if let Some(timings) = service.timings.lock().await.as_ref() {
+                println!("\t\tTimings (Last) => {}", timings);
+            }
+
             let check: Check = service.check.lock().await.clone();
 
-            println!("\t\tCheck Type => {}", check.check_type.to_string());
+            println!("\t\tCheck Type => {}", check.check_type);
             println!(
                 "\t\tFails Current => {}/{}",
                 check.fails_cur, service.fails_cnt_to_alert
@@ -2,6 +2,8 @@ use serde::Deserialize;
 
 use std::{collections::HashMap, fmt};
 
+use crate::config::{HttpAuthConfig, HttpProxyConfig};
+
 /* Defaults */
 // The default HTTP alert URL.
 // Should be localhost.
@@ -51,6 +53,21 @@ pub struct HttpAlert {
     pub body_is_file: bool,
 
     pub headers: Option<HashMap<String, String>>,
+
+    pub auth: Option<HttpAuthConfig>,
+
+    // If set, validates the server's chain against this PEM bundle instead of the system roots.
+    pub ca_file: Option<String>,
+
+    // PEM client certificate and key for mutual TLS (the key may be in the certificate file).
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+
+    // Base64 SHA-256 hashes of the server's public key (SPKI). Any one must match.
+    #[serde(default)]
+    pub pins: Vec<String>,
+
+    pub proxy: Option<HttpProxyConfig>,
 }
 
 #[derive(Deserialize, Debug, PartialEq, Clone)]
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+
+use std::fmt;
+
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub enum HttpAuthType {
+    #[serde(rename = "basic")]
+    BASIC,
+
+    #[serde(rename = "bearer")]
+    BEARER,
+
+    #[serde(rename = "oauth2")]
+    OAUTH2,
+}
+
+impl fmt::Display for HttpAuthType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// Which fields are required depends on the auth type.
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpAuthConfig {
+    #[serde(rename = "type")]
+    pub auth_type: HttpAuthType,
+
+    // Basic auth.
+    pub username: Option<String>,
+    pub password: Option<String>,
+
+    // Static bearer token.
+    pub token: Option<String>,
+
+    // OAuth2 client credentials.
+    pub token_url: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+    pub scope: Option<String>,
+}
+
+// Secrets are left out so this is safe to print.
+impl fmt::Display for HttpAuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let na = String::from("N/A");
+
+        match self.auth_type {
+            HttpAuthType::BASIC => write!(
+                f,
+                "{} (username: {})",
+                self.auth_type,
+                self.username.as_ref().unwrap_or(&na)
+            ),
+            HttpAuthType::BEARER => write!(f, "{}", self.auth_type),
+            HttpAuthType::OAUTH2 => write!(
+                f,
+                "{} (client ID: {}, token URL: {})",
+                self.auth_type,
+                self.client_id.as_ref().unwrap_or(&na),
+                self.token_url.as_ref().unwrap_or(&na)
+            ),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default mount points to check.
+fn def_disk_mounts() -> Vec<String> {
+    vec![String::from("/")]
+}
+
+// Thresholds are all optional. Percentages are 0 - 100 and free space is in megabytes.
+#[derive(Deserialize, Debug, Clone)]
+pub struct DiskCheckConfig {
+    #[serde(default = "def_disk_mounts")]
+    pub mounts: Vec<String>,
+
+    pub warn_used_pct: Option<f64>,
+    pub fail_used_pct: Option<f64>,
+
+    pub warn_free_mb: Option<u64>,
+    pub fail_free_mb: Option<u64>,
+
+    pub warn_inodes_pct: Option<f64>,
+    pub fail_inodes_pct: Option<f64>,
+}
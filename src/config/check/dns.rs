@@ -0,0 +1,61 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default DNS nameserver.
+// Should be a local resolver.
+fn def_dns_server() -> String {
+    String::from("127.0.0.1:53")
+}
+
+// The default name to query.
+fn def_dns_name() -> String {
+    String::from("localhost")
+}
+
+// The default record type to query.
+fn def_dns_record_type() -> String {
+    String::from("A")
+}
+
+// The default DNS timeout.
+// This is in seconds.
+fn def_dns_timeout() -> u64 {
+    5
+}
+
+// The default expected response code.
+fn def_dns_rcode() -> String {
+    String::from("NOERROR")
+}
+
+// The default recursion desired flag.
+fn def_dns_recursion() -> bool {
+    true
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DnsCheckConfig {
+    #[serde(default = "def_dns_server")]
+    pub server: String,
+
+    #[serde(default = "def_dns_name")]
+    pub name: String,
+
+    #[serde(default = "def_dns_record_type")]
+    pub record_type: String,
+
+    #[serde(default = "def_dns_timeout")]
+    pub timeout: u64,
+
+    #[serde(default = "def_dns_recursion")]
+    pub recursion: bool,
+
+    #[serde(default = "def_dns_rcode")]
+    pub rcode: String,
+
+    // If set, the answer set must match these values exactly (order doesn't matter).
+    pub answers: Option<Vec<String>>,
+
+    // If set, fails when the query time (in milliseconds) exceeds this.
+    pub max_query_time: Option<u32>,
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+/* Defaults */
+// The default exec timeout.
+// This is in seconds. The process is killed once it's reached.
+fn def_exec_timeout() -> u64 {
+    10
+}
+
+// The default exit codes that are considered passing.
+fn def_exec_exit_codes() -> Vec<i32> {
+    vec![0]
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ExecCheckConfig {
+    pub program: String,
+
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    pub env: Option<HashMap<String, String>>,
+
+    // If set, the program is ran from this directory.
+    pub cwd: Option<String>,
+
+    #[serde(default = "def_exec_timeout")]
+    pub timeout: u64,
+
+    #[serde(default = "def_exec_exit_codes")]
+    pub exit_codes: Vec<i32>,
+
+    // If set, stdout must match this regex.
+    pub stdout: Option<String>,
+
+    // If set, stderr must match this regex.
+    pub stderr: Option<String>,
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+// The path may be a glob pattern, in which case the newest matching file is checked.
+// Ages are in seconds and sizes are in bytes.
+#[derive(Deserialize, Debug, Clone)]
+pub struct FileCheckConfig {
+    pub path: String,
+
+    pub max_age: Option<u64>,
+
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+}
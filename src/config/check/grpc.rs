@@ -0,0 +1,66 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+/* Defaults */
+// The default gRPC host.
+// Should be localhost.
+fn def_grpc_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default gRPC port.
+fn def_grpc_port() -> u16 {
+    50051
+}
+
+// The default gRPC timeout.
+// This is in seconds.
+fn def_grpc_timeout() -> u64 {
+    10
+}
+
+// The default gRPC TLS flag.
+// Without TLS, we speak HTTP/2 in plain text (h2c).
+fn def_grpc_tls() -> bool {
+    false
+}
+
+// The default gRPC insecure flag.
+// If enabled, accepts servers with invalid certs or hostnames.
+fn def_grpc_is_insecure() -> bool {
+    false
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GrpcCheckConfig {
+    #[serde(default = "def_grpc_host")]
+    pub host: String,
+
+    #[serde(default = "def_grpc_port")]
+    pub port: u16,
+
+    #[serde(default = "def_grpc_timeout")]
+    pub timeout: u64,
+
+    // The service to ask about. If empty, asks about the server as a whole.
+    #[serde(default)]
+    pub service: String,
+
+    #[serde(default = "def_grpc_tls")]
+    pub tls: bool,
+
+    #[serde(default = "def_grpc_is_insecure")]
+    pub is_insecure: bool,
+
+    // If set, validates the server's chain against this PEM bundle instead of the system roots.
+    pub ca_file: Option<String>,
+
+    // PEM client certificate and key for mutual TLS (the key may be in the certificate file).
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+
+    // Extra request metadata (e.g. an authorization token).
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
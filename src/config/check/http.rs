@@ -0,0 +1,141 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+use crate::config::{HttpAuthConfig, HttpProxyConfig};
+use crate::helper::HTTP_OK_CODES;
+
+/* Defaults */
+// The default HTTP URL.
+// Should be localhost.
+fn def_http_url() -> String {
+    String::from("http://127.0.0.1")
+}
+
+// The default HTTP method.
+// Most popular is GET.
+fn def_http_method() -> String {
+    String::from("GET")
+}
+
+// The default HTTP timeout.
+// This is in seconds.
+fn def_http_timeout() -> u64 {
+    10
+}
+
+// The default HTTP alert body file flag.
+fn def_body_is_file() -> bool {
+    false
+}
+
+// The default HTTP insecure flag.
+// If enabled, accepts server responses with invalid certs or hostnames.
+fn def_http_is_insecure() -> bool {
+    false
+}
+
+// The default HTTP redirect flag.
+fn def_http_follow_redirects() -> bool {
+    true
+}
+
+// The default max amount of redirects to follow.
+fn def_http_max_redirects() -> usize {
+    10
+}
+
+// The default HTTP fan out flag.
+fn def_http_fan_out() -> bool {
+    false
+}
+
+// We'll want to add the most popular success codes by default (200 - 206)
+fn def_http_accept_codes() -> Vec<u16> {
+    HTTP_OK_CODES.to_vec()
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpCheckConfig {
+    #[serde(default = "def_http_method")]
+    pub method: String,
+
+    #[serde(default = "def_http_url")]
+    pub url: String,
+
+    #[serde(default = "def_http_timeout")]
+    pub timeout: u64,
+
+    #[serde(default = "def_http_is_insecure")]
+    pub is_insecure: bool,
+
+    pub body: Option<String>,
+
+    #[serde(default = "def_body_is_file")]
+    pub body_is_file: bool,
+
+    pub headers: Option<HashMap<String, String>>,
+
+    #[serde(default = "def_http_accept_codes")]
+    pub accept_codes: Vec<u16>,
+
+    // Response body assertions. Every one that's set must pass.
+    #[serde(default)]
+    pub expect_contains: Vec<String>,
+
+    #[serde(default)]
+    pub expect_not_contains: Vec<String>,
+
+    pub expect_regex: Option<String>,
+
+    // JSON pointer assertions (e.g. `/status == "ok"` or `/queue/depth < 100`).
+    #[serde(default)]
+    pub expect_json: Vec<String>,
+
+    // Response header assertions (header name => exact value or regex).
+    #[serde(default)]
+    pub expect_headers: HashMap<String, String>,
+
+    #[serde(default)]
+    pub expect_headers_regex: HashMap<String, String>,
+
+    // Response body size limits in bytes.
+    pub min_body_size: Option<u64>,
+    pub max_body_size: Option<u64>,
+
+    #[serde(default = "def_http_follow_redirects")]
+    pub follow_redirects: bool,
+
+    #[serde(default = "def_http_max_redirects")]
+    pub max_redirects: usize,
+
+    // Regexes the final URL (after redirects) and the Location header must match.
+    pub expect_url: Option<String>,
+    pub expect_location: Option<String>,
+
+    pub auth: Option<HttpAuthConfig>,
+
+    // If set, validates the server's chain against this PEM bundle instead of the system roots.
+    pub ca_file: Option<String>,
+
+    // PEM client certificate and key for mutual TLS (the key may be in the certificate file).
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+
+    // Base64 SHA-256 hashes of the server's public key (SPKI). Any one must match.
+    #[serde(default)]
+    pub pins: Vec<String>,
+
+    pub proxy: Option<HttpProxyConfig>,
+
+    // If set, connects to these IPs instead of resolving the URL's host (like curl's --resolve).
+    #[serde(default)]
+    pub resolve: Vec<String>,
+
+    // If set (4 or 6), only connects over this IP version.
+    pub ip_version: Option<u8>,
+
+    // If enabled, every address is checked separately and all of them must pass.
+    #[serde(default = "def_http_fan_out")]
+    pub fan_out: bool,
+}
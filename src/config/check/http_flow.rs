@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use std::collections::HashMap;
+
+use crate::config::HttpCheckConfig;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpFlowStepConfig {
+    // Used in error messages (defaults to the step's position).
+    pub name: Option<String>,
+
+    // Each step is a full HTTP check.
+    #[serde(flatten)]
+    pub http: HttpCheckConfig,
+
+    // Values to pull out of the response (variable name => capture), used as `{name}` later on.
+    #[serde(default)]
+    pub capture: HashMap<String, String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpFlowCheckConfig {
+    // Steps run in order and the flow stops at the first one that fails.
+    pub steps: Vec<HttpFlowStepConfig>,
+}
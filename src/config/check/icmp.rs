@@ -0,0 +1,52 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default ICMP host.
+// Should be localhost.
+fn def_icmp_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default amount of echo requests to send per check.
+fn def_icmp_count() -> u16 {
+    3
+}
+
+// The default delay between echo requests.
+// This is in milliseconds.
+fn def_icmp_interval() -> u64 {
+    200
+}
+
+// The default time to wait for each echo reply.
+// This is in seconds.
+fn def_icmp_timeout() -> u64 {
+    2
+}
+
+// The default max packet loss before failing.
+// This is a percentage (0 - 100).
+fn def_icmp_max_loss() -> f64 {
+    50.0
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct IcmpCheckConfig {
+    #[serde(default = "def_icmp_host")]
+    pub host: String,
+
+    #[serde(default = "def_icmp_count")]
+    pub count: u16,
+
+    #[serde(default = "def_icmp_interval")]
+    pub interval: u64,
+
+    #[serde(default = "def_icmp_timeout")]
+    pub timeout: u64,
+
+    #[serde(default = "def_icmp_max_loss")]
+    pub max_loss: f64,
+
+    // If set, fails when the average RTT (in milliseconds) exceeds this.
+    pub max_rtt_avg: Option<u32>,
+}
@@ -0,0 +1,157 @@
+mod disk;
+mod dns;
+mod exec;
+mod file;
+mod grpc;
+mod http;
+mod http_flow;
+mod icmp;
+mod mysql;
+mod postgres;
+mod process;
+mod redis;
+mod smtp;
+mod system;
+mod tcp;
+mod tls;
+mod udp;
+
+pub use disk::DiskCheckConfig;
+pub use dns::DnsCheckConfig;
+pub use exec::ExecCheckConfig;
+pub use file::FileCheckConfig;
+pub use grpc::GrpcCheckConfig;
+pub use http::HttpCheckConfig;
+pub use http_flow::{HttpFlowCheckConfig, HttpFlowStepConfig};
+pub use icmp::IcmpCheckConfig;
+pub use mysql::MysqlCheckConfig;
+pub use postgres::PostgresCheckConfig;
+pub use process::ProcessCheckConfig;
+pub use redis::RedisCheckConfig;
+pub use smtp::SmtpCheckConfig;
+pub use system::SystemCheckConfig;
+pub use tcp::TcpCheckConfig;
+pub use tls::TlsCheckConfig;
+pub use udp::UdpCheckConfig;
+
+use serde::Deserialize;
+
+use std::fmt::Display;
+
+/* Defaults */
+pub fn def_cron() -> String {
+    String::from("0 * * * * *")
+}
+
+// The default check type.
+fn def_check_type() -> CheckType {
+    CheckType::HTTP
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum CheckType {
+    #[serde(rename = "http")]
+    HTTP,
+
+    #[serde(rename = "tcp")]
+    TCP,
+
+    #[serde(rename = "udp")]
+    UDP,
+
+    #[serde(rename = "icmp")]
+    ICMP,
+
+    #[serde(rename = "dns")]
+    DNS,
+
+    #[serde(rename = "tls")]
+    TLS,
+
+    #[serde(rename = "exec")]
+    EXEC,
+
+    #[serde(rename = "process")]
+    PROCESS,
+
+    #[serde(rename = "disk")]
+    DISK,
+
+    #[serde(rename = "system")]
+    SYSTEM,
+
+    #[serde(rename = "file")]
+    FILE,
+
+    #[serde(rename = "http_flow")]
+    #[allow(non_camel_case_types)]
+    HTTP_FLOW,
+
+    #[serde(rename = "redis")]
+    REDIS,
+
+    #[serde(rename = "postgres")]
+    POSTGRES,
+
+    #[serde(rename = "mysql")]
+    MYSQL,
+
+    #[serde(rename = "smtp")]
+    SMTP,
+
+    #[serde(rename = "grpc")]
+    GRPC,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Check {
+    #[serde(default = "def_cron")]
+    pub cron: String,
+
+    #[serde(rename = "type", default = "def_check_type")]
+    pub check_type: CheckType,
+
+    pub http: Option<HttpCheckConfig>,
+    pub tcp: Option<TcpCheckConfig>,
+    pub udp: Option<UdpCheckConfig>,
+    pub icmp: Option<IcmpCheckConfig>,
+    pub dns: Option<DnsCheckConfig>,
+    pub tls: Option<TlsCheckConfig>,
+    pub exec: Option<ExecCheckConfig>,
+    pub process: Option<ProcessCheckConfig>,
+    pub disk: Option<DiskCheckConfig>,
+    pub system: Option<SystemCheckConfig>,
+    pub file: Option<FileCheckConfig>,
+    pub http_flow: Option<HttpFlowCheckConfig>,
+    pub redis: Option<RedisCheckConfig>,
+    pub postgres: Option<PostgresCheckConfig>,
+    pub mysql: Option<MysqlCheckConfig>,
+    pub smtp: Option<SmtpCheckConfig>,
+    pub grpc: Option<GrpcCheckConfig>,
+}
+
+impl Display for CheckType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str_f = match self {
+            CheckType::HTTP => "HTTP",
+            CheckType::TCP => "TCP",
+            CheckType::UDP => "UDP",
+            CheckType::ICMP => "ICMP",
+            CheckType::DNS => "DNS",
+            CheckType::TLS => "TLS",
+            CheckType::EXEC => "EXEC",
+            CheckType::PROCESS => "PROCESS",
+            CheckType::DISK => "DISK",
+            CheckType::SYSTEM => "SYSTEM",
+            CheckType::FILE => "FILE",
+            CheckType::HTTP_FLOW => "HTTP_FLOW",
+            CheckType::REDIS => "REDIS",
+            CheckType::POSTGRES => "POSTGRES",
+            CheckType::MYSQL => "MYSQL",
+            CheckType::SMTP => "SMTP",
+            CheckType::GRPC => "GRPC",
+        };
+
+        write!(f, "{:?}", str_f)
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default MySQL host.
+// Should be localhost.
+fn def_mysql_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default MySQL port.
+fn def_mysql_port() -> u16 {
+    3306
+}
+
+// The default MySQL timeout.
+// This is in seconds and covers connecting and running the query.
+fn def_mysql_timeout() -> u64 {
+    10
+}
+
+// The default MySQL user.
+fn def_mysql_user() -> String {
+    String::from("root")
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MysqlCheckConfig {
+    #[serde(default = "def_mysql_host")]
+    pub host: String,
+
+    #[serde(default = "def_mysql_port")]
+    pub port: u16,
+
+    #[serde(default = "def_mysql_timeout")]
+    pub timeout: u64,
+
+    #[serde(default = "def_mysql_user")]
+    pub user: String,
+
+    pub password: Option<String>,
+    pub database: Option<String>,
+
+    // If not set, we only log in and ping the server.
+    pub query: Option<String>,
+
+    // Column assertions against the first row (e.g. `Seconds_Behind_Source < 30`).
+    #[serde(default)]
+    pub expect: Vec<String>,
+}
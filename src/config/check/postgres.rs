@@ -0,0 +1,79 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default Postgres host.
+// Should be localhost.
+fn def_postgres_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default Postgres port.
+fn def_postgres_port() -> u16 {
+    5432
+}
+
+// The default Postgres timeout.
+// This is in seconds and covers connecting and running the query.
+fn def_postgres_timeout() -> u64 {
+    10
+}
+
+// The default Postgres user.
+fn def_postgres_user() -> String {
+    String::from("postgres")
+}
+
+// The default TLS mode.
+// Like libpq, we use TLS if the server supports it without verifying the certificate.
+fn def_postgres_tls_mode() -> String {
+    String::from("prefer")
+}
+
+// The default query to run.
+fn def_postgres_query() -> String {
+    String::from("SELECT 1")
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PostgresCheckConfig {
+    #[serde(default = "def_postgres_host")]
+    pub host: String,
+
+    #[serde(default = "def_postgres_port")]
+    pub port: u16,
+
+    #[serde(default = "def_postgres_timeout")]
+    pub timeout: u64,
+
+    #[serde(default = "def_postgres_user")]
+    pub user: String,
+
+    pub password: Option<String>,
+
+    // Defaults to the user's name (like libpq).
+    pub database: Option<String>,
+
+    // One of disable, prefer, require, or verify-full.
+    #[serde(default = "def_postgres_tls_mode")]
+    pub tls_mode: String,
+
+    // Used with verify-full instead of the system roots.
+    pub ca_file: Option<String>,
+
+    // PEM client certificate and key (the key may be in the certificate file).
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+
+    #[serde(default = "def_postgres_query")]
+    pub query: String,
+
+    // If set, the first cell of the first row must equal this.
+    pub expect: Option<String>,
+
+    // Numeric thresholds for the first cell.
+    pub warn_min: Option<f64>,
+    pub fail_min: Option<f64>,
+
+    pub warn_max: Option<f64>,
+    pub fail_max: Option<f64>,
+}
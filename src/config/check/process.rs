@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default process match flag.
+// If false, the pattern is matched against the process name instead of the full command line.
+fn def_process_match_cmdline() -> bool {
+    false
+}
+
+// The default min amount of matching processes.
+fn def_process_min() -> u32 {
+    1
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProcessCheckConfig {
+    // The regex to match processes against.
+    pub pattern: Option<String>,
+
+    #[serde(default = "def_process_match_cmdline")]
+    pub match_cmdline: bool,
+
+    #[serde(default = "def_process_min")]
+    pub min: u32,
+
+    pub max: Option<u32>,
+
+    // If set, fails when a matching process's RSS (in megabytes) exceeds this.
+    pub max_rss: Option<u64>,
+
+    // If set, fails when a matching process's CPU time (in seconds) exceeds this.
+    pub max_cpu_time: Option<u64>,
+
+    // If set, the process ID in this file must be running.
+    pub pidfile: Option<String>,
+}
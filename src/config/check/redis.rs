@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default Redis host.
+// Should be localhost.
+fn def_redis_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default Redis port.
+fn def_redis_port() -> u16 {
+    6379
+}
+
+// The default Redis timeout.
+// This is in seconds and covers the entire exchange.
+fn def_redis_timeout() -> u64 {
+    10
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RedisCheckConfig {
+    #[serde(default = "def_redis_host")]
+    pub host: String,
+
+    #[serde(default = "def_redis_port")]
+    pub port: u16,
+
+    #[serde(default = "def_redis_timeout")]
+    pub timeout: u64,
+
+    // If a password is set, we AUTH before anything else (with the username for ACL users).
+    pub username: Option<String>,
+    pub password: Option<String>,
+
+    // The expected replication role (master or replica).
+    pub role: Option<String>,
+
+    // Fail if fewer replicas than this are connected.
+    pub min_replicas: Option<u64>,
+
+    pub warn_used_memory_mb: Option<u64>,
+    pub fail_used_memory_mb: Option<u64>,
+
+    pub warn_blocked_clients: Option<u64>,
+    pub fail_blocked_clients: Option<u64>,
+}
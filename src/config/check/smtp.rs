@@ -0,0 +1,96 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default SMTP host.
+// Should be localhost.
+fn def_smtp_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default SMTP port.
+fn def_smtp_port() -> u16 {
+    25
+}
+
+// The default SMTP timeout.
+// This is in seconds and covers the entire session.
+fn def_smtp_timeout() -> u64 {
+    10
+}
+
+// The default name we introduce ourselves as in EHLO.
+fn def_smtp_ehlo_name() -> String {
+    String::from("localhost")
+}
+
+// The default STARTTLS flag.
+fn def_smtp_starttls() -> bool {
+    false
+}
+
+// The default SMTP insecure flag.
+// If enabled, the certificate chain isn't validated (expiry and hostname are still checked).
+fn def_smtp_is_insecure() -> bool {
+    false
+}
+
+// The default amount of days left on the certificate before warning.
+fn def_smtp_warn_days() -> i64 {
+    30
+}
+
+// The default amount of days left on the certificate before failing.
+fn def_smtp_fail_days() -> i64 {
+    7
+}
+
+// The default plaintext AUTH flag.
+// If disabled, we refuse to send credentials unless STARTTLS is used.
+fn def_smtp_allow_plaintext_auth() -> bool {
+    false
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SmtpCheckConfig {
+    #[serde(default = "def_smtp_host")]
+    pub host: String,
+
+    #[serde(default = "def_smtp_port")]
+    pub port: u16,
+
+    #[serde(default = "def_smtp_timeout")]
+    pub timeout: u64,
+
+    #[serde(default = "def_smtp_ehlo_name")]
+    pub ehlo_name: String,
+
+    // Extensions the server must advertise in its EHLO reply (e.g. SIZE or 8BITMIME).
+    #[serde(default)]
+    pub expect_extensions: Vec<String>,
+
+    // If enabled, the session is upgraded with STARTTLS and the certificate is checked.
+    #[serde(default = "def_smtp_starttls")]
+    pub starttls: bool,
+
+    // If set, sent as the SNI and used for hostname matching instead of the host.
+    pub sni: Option<String>,
+
+    #[serde(default = "def_smtp_is_insecure")]
+    pub is_insecure: bool,
+
+    // If set, validates the chain against this PEM bundle instead of the system roots.
+    pub ca_file: Option<String>,
+
+    #[serde(default = "def_smtp_warn_days")]
+    pub warn_days: i64,
+
+    #[serde(default = "def_smtp_fail_days")]
+    pub fail_days: i64,
+
+    // If set, we AUTH (PLAIN or LOGIN) after EHLO.
+    pub username: Option<String>,
+    pub password: Option<String>,
+
+    #[serde(default = "def_smtp_allow_plaintext_auth")]
+    pub allow_plaintext_auth: bool,
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default load average to compare against (1, 5, or 15 minutes).
+fn def_system_load_avg() -> u8 {
+    5
+}
+
+// The default PSI average to compare against (10, 60, or 300 seconds).
+fn def_system_pressure_avg() -> u16 {
+    60
+}
+
+// Thresholds are all optional. Percentages are 0 - 100.
+#[derive(Deserialize, Debug, Clone)]
+pub struct SystemCheckConfig {
+    #[serde(default = "def_system_load_avg")]
+    pub load_avg: u8,
+
+    pub warn_load_per_core: Option<f64>,
+    pub fail_load_per_core: Option<f64>,
+
+    pub warn_mem_avail_pct: Option<f64>,
+    pub fail_mem_avail_pct: Option<f64>,
+
+    pub warn_swap_used_pct: Option<f64>,
+    pub fail_swap_used_pct: Option<f64>,
+
+    #[serde(default = "def_system_pressure_avg")]
+    pub pressure_avg: u16,
+
+    pub warn_cpu_pressure: Option<f64>,
+    pub fail_cpu_pressure: Option<f64>,
+
+    pub warn_mem_pressure: Option<f64>,
+    pub fail_mem_pressure: Option<f64>,
+
+    pub warn_io_pressure: Option<f64>,
+    pub fail_io_pressure: Option<f64>,
+}
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default TCP host.
+// Should be localhost.
+fn def_tcp_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default TCP port.
+fn def_tcp_port() -> u16 {
+    80
+}
+
+// The default TCP timeout.
+// This is in seconds and covers connecting, sending, and reading the reply.
+fn def_tcp_timeout() -> u64 {
+    10
+}
+
+// The default max amount of bytes to read when matching the reply.
+fn def_tcp_read_max() -> usize {
+    4096
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TcpCheckConfig {
+    #[serde(default = "def_tcp_host")]
+    pub host: String,
+
+    #[serde(default = "def_tcp_port")]
+    pub port: u16,
+
+    #[serde(default = "def_tcp_timeout")]
+    pub timeout: u64,
+
+    // If set, this payload is written after connecting.
+    pub send: Option<String>,
+
+    // If set, the reply must match this regex.
+    pub expect: Option<String>,
+
+    #[serde(default = "def_tcp_read_max")]
+    pub read_max: usize,
+}
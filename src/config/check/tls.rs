@@ -0,0 +1,65 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default TLS host.
+// Should be localhost.
+fn def_tls_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default TLS port.
+fn def_tls_port() -> u16 {
+    443
+}
+
+// The default TLS timeout.
+// This is in seconds and covers connecting and the handshake.
+fn def_tls_timeout() -> u64 {
+    10
+}
+
+// The default TLS insecure flag.
+// If enabled, the certificate chain isn't validated (expiry and hostname are still checked).
+fn def_tls_is_insecure() -> bool {
+    false
+}
+
+// The default amount of days left before warning.
+fn def_tls_warn_days() -> i64 {
+    30
+}
+
+// The default amount of days left before failing.
+fn def_tls_fail_days() -> i64 {
+    7
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TlsCheckConfig {
+    #[serde(default = "def_tls_host")]
+    pub host: String,
+
+    #[serde(default = "def_tls_port")]
+    pub port: u16,
+
+    // If set, sent as the SNI and used for hostname matching instead of the host.
+    pub sni: Option<String>,
+
+    #[serde(default = "def_tls_timeout")]
+    pub timeout: u64,
+
+    #[serde(default = "def_tls_is_insecure")]
+    pub is_insecure: bool,
+
+    // If set, validates the chain against this PEM bundle instead of the system roots.
+    pub ca_file: Option<String>,
+
+    #[serde(default = "def_tls_warn_days")]
+    pub warn_days: i64,
+
+    #[serde(default = "def_tls_fail_days")]
+    pub fail_days: i64,
+
+    // If set, the leaf certificate must cover each of these names.
+    pub sans: Option<Vec<String>>,
+}
@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+/* Defaults */
+// The default UDP host.
+// Should be localhost.
+fn def_udp_host() -> String {
+    String::from("127.0.0.1")
+}
+
+// The default UDP port.
+fn def_udp_port() -> u16 {
+    53
+}
+
+// The default UDP timeout.
+// This is in seconds and covers sending and waiting for the reply.
+fn def_udp_timeout() -> u64 {
+    10
+}
+
+// The default UDP hex payload flag.
+fn def_udp_send_is_hex() -> bool {
+    false
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UdpCheckConfig {
+    #[serde(default = "def_udp_host")]
+    pub host: String,
+
+    #[serde(default = "def_udp_port")]
+    pub port: u16,
+
+    #[serde(default = "def_udp_timeout")]
+    pub timeout: u64,
+
+    // The datagram payload to send.
+    #[serde(default)]
+    pub send: String,
+
+    // If true, the payload is parsed as a hex string (e.g. "ffffffff54").
+    #[serde(default = "def_udp_send_is_hex")]
+    pub send_is_hex: bool,
+
+    // If set, the reply must match this regex.
+    pub expect: Option<String>,
+}
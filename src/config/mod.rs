@@ -1,10 +1,19 @@
 mod alert;
+mod auth;
 mod check;
+mod proxy;
 mod service;
 mod utils;
 
 pub use alert::{Alert, AlertType, HttpAlert};
-pub use check::{Check, CheckType, HttpCheckConfig};
+pub use auth::{HttpAuthConfig, HttpAuthType};
+pub use check::{
+    Check, CheckType, DiskCheckConfig, DnsCheckConfig, ExecCheckConfig, FileCheckConfig,
+    GrpcCheckConfig, HttpCheckConfig, HttpFlowCheckConfig, HttpFlowStepConfig, IcmpCheckConfig,
+    MysqlCheckConfig, PostgresCheckConfig, ProcessCheckConfig, RedisCheckConfig, SmtpCheckConfig,
+    SystemCheckConfig, TcpCheckConfig, TlsCheckConfig, UdpCheckConfig,
+};
+pub use proxy::HttpProxyConfig;
 pub use service::Service;
 
 use serde::Deserialize;
@@ -49,9 +58,14 @@ impl Config {
 
         let new_cfg: Config = serde_json::from_str(&contents)?;
 
-        // Since we can't reassign self directly, use mem::replace() to update config structure.
-        match std::mem::replace(self, new_cfg) {
-            _ => Ok(()),
-        }
+        *self = new_cfg;
+
+        Ok(())
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -0,0 +1,34 @@
+use reqwest::Url;
+use serde::Deserialize;
+
+// The URL's scheme picks the proxy type (e.g. "http://proxy:3128" or "socks5h://proxy:1080").
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpProxyConfig {
+    pub url: String,
+
+    pub username: Option<String>,
+    pub password: Option<String>,
+
+    // Hosts, domains, or CIDRs that should be reached directly instead.
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+}
+
+impl HttpProxyConfig {
+    // The URL to show in output, with any password (inline or configured) masked.
+    pub fn masked_url(&self) -> String {
+        let Ok(mut url) = Url::parse(&self.url) else {
+            return self.url.clone();
+        };
+
+        if let Some(username) = &self.username {
+            let _ = url.set_username(username);
+        }
+
+        if url.password().is_some() || self.password.is_some() {
+            let _ = url.set_password(Some("********"));
+        }
+
+        url.to_string()
+    }
+}
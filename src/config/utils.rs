@@ -18,22 +18,16 @@ impl Config {
 
         println!("Log directory: {}", log_dir);
 
-        if self.services.len() > 0 {
+        if !self.services.is_empty() {
             println!("Services:");
             for service in self.services.iter() {
                 println!("\t{}", service.name);
 
-                let fails_cnt_to_alert = match service.fails_cnt_to_alert {
-                    Some(cnt) => cnt,
-                    None => 0,
-                };
+                let fails_cnt_to_alert = service.fails_cnt_to_alert.unwrap_or_default();
 
                 println!("\t\tFails Count To Alert => {}", fails_cnt_to_alert);
 
-                let lats_max_track = match service.lats_max_track {
-                    Some(cnt) => cnt,
-                    None => 0,
-                };
+                let lats_max_track = service.lats_max_track.unwrap_or_default();
 
                 println!("\t\tLatency Max Track => {}", lats_max_track);
 
@@ -63,7 +57,7 @@ impl Config {
                     );
 
                     if let Some(headers) = &http.headers
-                        && headers.len() > 0
+                        && !headers.is_empty()
                     {
                         println!("\t\t\t\tHeaders:");
                         for (key, val) in headers {
@@ -81,6 +75,576 @@ impl Config {
                             .collect::<Vec<_>>()
                             .join(", ")
                     );
+
+                    if let Some(auth) = &http.auth {
+                        println!("\t\t\t\tAuth: {}", auth);
+                    }
+
+                    if let Some(ca_file) = &http.ca_file {
+                        println!("\t\t\t\tCA File: {}", ca_file);
+                    }
+
+                    if let Some(client_cert) = &http.client_cert {
+                        println!("\t\t\t\tClient Cert: {}", client_cert);
+                    }
+
+                    if let Some(client_key) = &http.client_key {
+                        println!("\t\t\t\tClient Key: {}", client_key);
+                    }
+
+                    if !http.pins.is_empty() {
+                        println!("\t\t\t\tPins: {}", http.pins.join(", "));
+                    }
+
+                    if let Some(proxy) = &http.proxy {
+                        println!("\t\t\t\tProxy: {}", proxy.masked_url());
+
+                        if !proxy.no_proxy.is_empty() {
+                            println!("\t\t\t\tNo Proxy: {}", proxy.no_proxy.join(", "));
+                        }
+                    }
+
+                    if !http.expect_contains.is_empty() {
+                        println!(
+                            "\t\t\t\tExpect Contains: {}",
+                            http.expect_contains.join(", ")
+                        );
+                    }
+
+                    if !http.expect_not_contains.is_empty() {
+                        println!(
+                            "\t\t\t\tExpect Not Contains: {}",
+                            http.expect_not_contains.join(", ")
+                        );
+                    }
+
+                    if let Some(expect_regex) = &http.expect_regex {
+                        println!("\t\t\t\tExpect Regex: {}", expect_regex);
+                    }
+
+                    if !http.expect_json.is_empty() {
+                        println!("\t\t\t\tExpect JSON:");
+                        for expr in http.expect_json.iter() {
+                            println!("\t\t\t\t\t{}", expr);
+                        }
+                    }
+
+                    if !http.expect_headers.is_empty() {
+                        println!("\t\t\t\tExpect Headers:");
+                        for (key, val) in http.expect_headers.iter() {
+                            println!("\t\t\t\t\t{}: {}", key, val);
+                        }
+                    }
+
+                    if !http.expect_headers_regex.is_empty() {
+                        println!("\t\t\t\tExpect Headers (Regex):");
+                        for (key, val) in http.expect_headers_regex.iter() {
+                            println!("\t\t\t\t\t{}: {}", key, val);
+                        }
+                    }
+
+                    if http.min_body_size.is_some() || http.max_body_size.is_some() {
+                        let fmt_opt = |val: Option<u64>| match val {
+                            Some(bytes) => format!("{} bytes", bytes),
+                            None => String::from("N/A"),
+                        };
+
+                        println!(
+                            "\t\t\t\tBody Size (Min/Max): {}/{}",
+                            fmt_opt(http.min_body_size),
+                            fmt_opt(http.max_body_size)
+                        );
+                    }
+
+                    println!("\t\t\t\tFollow Redirects => {}", http.follow_redirects);
+                    println!("\t\t\t\tMax Redirects: {}", http.max_redirects);
+
+                    if let Some(expect_url) = &http.expect_url {
+                        println!("\t\t\t\tExpect URL: {}", expect_url);
+                    }
+
+                    if let Some(expect_location) = &http.expect_location {
+                        println!("\t\t\t\tExpect Location: {}", expect_location);
+                    }
+
+                    if !http.resolve.is_empty() {
+                        println!("\t\t\t\tResolve: {}", http.resolve.join(", "));
+                    }
+
+                    if let Some(ip_version) = http.ip_version {
+                        println!("\t\t\t\tIP Version: IPv{}", ip_version);
+                    }
+
+                    println!("\t\t\t\tFan Out => {}", http.fan_out);
+                }
+
+                if let Some(tcp) = &check.tcp {
+                    println!("\t\t\tTCP Settings:");
+                    println!("\t\t\t\tHost: {}", tcp.host);
+                    println!("\t\t\t\tPort: {}", tcp.port);
+                    println!("\t\t\t\tTimeout: {}", tcp.timeout);
+
+                    println!(
+                        "\t\t\t\tSend: {}",
+                        match &tcp.send {
+                            Some(send) => send.escape_debug().to_string(),
+                            None => String::from("N/A"),
+                        }
+                    );
+                    println!(
+                        "\t\t\t\tExpect: {}",
+                        tcp.expect.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!("\t\t\t\tRead Max: {}", tcp.read_max);
+                }
+
+                if let Some(udp) = &check.udp {
+                    println!("\t\t\tUDP Settings:");
+                    println!("\t\t\t\tHost: {}", udp.host);
+                    println!("\t\t\t\tPort: {}", udp.port);
+                    println!("\t\t\t\tTimeout: {}", udp.timeout);
+
+                    println!("\t\t\t\tSend: {}", udp.send.escape_debug());
+                    println!("\t\t\t\tSend Is Hex => {}", udp.send_is_hex);
+                    println!(
+                        "\t\t\t\tExpect: {}",
+                        udp.expect.clone().unwrap_or(String::from("N/A"))
+                    );
+                }
+
+                if let Some(icmp) = &check.icmp {
+                    println!("\t\t\tICMP Settings:");
+                    println!("\t\t\t\tHost: {}", icmp.host);
+                    println!("\t\t\t\tCount: {}", icmp.count);
+                    println!("\t\t\t\tInterval: {}ms", icmp.interval);
+                    println!("\t\t\t\tTimeout: {}", icmp.timeout);
+                    println!("\t\t\t\tMax Loss: {}%", icmp.max_loss);
+                    println!(
+                        "\t\t\t\tMax RTT Avg: {}",
+                        match icmp.max_rtt_avg {
+                            Some(rtt) => format!("{}ms", rtt),
+                            None => String::from("N/A"),
+                        }
+                    );
+                }
+
+                if let Some(dns) = &check.dns {
+                    println!("\t\t\tDNS Settings:");
+                    println!("\t\t\t\tServer: {}", dns.server);
+                    println!("\t\t\t\tName: {}", dns.name);
+                    println!("\t\t\t\tRecord Type: {}", dns.record_type);
+                    println!("\t\t\t\tTimeout: {}", dns.timeout);
+                    println!("\t\t\t\tRecursion => {}", dns.recursion);
+                    println!("\t\t\t\tResponse Code: {}", dns.rcode);
+
+                    if let Some(answers) = &dns.answers {
+                        println!("\t\t\t\tAnswers: {}", answers.join(", "));
+                    }
+
+                    println!(
+                        "\t\t\t\tMax Query Time: {}",
+                        match dns.max_query_time {
+                            Some(time) => format!("{}ms", time),
+                            None => String::from("N/A"),
+                        }
+                    );
+                }
+
+                if let Some(tls) = &check.tls {
+                    println!("\t\t\tTLS Settings:");
+                    println!("\t\t\t\tHost: {}", tls.host);
+                    println!("\t\t\t\tPort: {}", tls.port);
+                    println!(
+                        "\t\t\t\tSNI: {}",
+                        tls.sni.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!("\t\t\t\tTimeout: {}", tls.timeout);
+                    println!("\t\t\t\tIs Insecure => {}", tls.is_insecure);
+                    println!(
+                        "\t\t\t\tCA File: {}",
+                        tls.ca_file.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!("\t\t\t\tWarn Days: {}", tls.warn_days);
+                    println!("\t\t\t\tFail Days: {}", tls.fail_days);
+
+                    if let Some(sans) = &tls.sans {
+                        println!("\t\t\t\tSANs: {}", sans.join(", "));
+                    }
+                }
+
+                if let Some(exec) = &check.exec {
+                    println!("\t\t\tExec Settings:");
+                    println!("\t\t\t\tProgram: {}", exec.program);
+                    println!("\t\t\t\tArgs: {}", exec.args.join(" "));
+
+                    if let Some(env) = &exec.env
+                        && !env.is_empty()
+                    {
+                        // Env often carries tokens, so only the names are shown.
+                        println!("\t\t\t\tEnv:");
+                        for key in env.keys() {
+                            println!("\t\t\t\t\t{}=********", key);
+                        }
+                    }
+
+                    println!(
+                        "\t\t\t\tCwd: {}",
+                        exec.cwd.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!("\t\t\t\tTimeout: {}", exec.timeout);
+                    println!(
+                        "\t\t\t\tExit Codes: {}",
+                        exec.exit_codes
+                            .iter()
+                            .map(|code| code.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    );
+                    println!(
+                        "\t\t\t\tStdout: {}",
+                        exec.stdout.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!(
+                        "\t\t\t\tStderr: {}",
+                        exec.stderr.clone().unwrap_or(String::from("N/A"))
+                    );
+                }
+
+                if let Some(process) = &check.process {
+                    println!("\t\t\tProcess Settings:");
+                    println!(
+                        "\t\t\t\tPattern: {}",
+                        process.pattern.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!("\t\t\t\tMatch Cmdline => {}", process.match_cmdline);
+                    println!("\t\t\t\tMin: {}", process.min);
+                    println!(
+                        "\t\t\t\tMax: {}",
+                        match process.max {
+                            Some(max) => max.to_string(),
+                            None => String::from("N/A"),
+                        }
+                    );
+                    println!(
+                        "\t\t\t\tMax RSS: {}",
+                        match process.max_rss {
+                            Some(rss) => format!("{}MB", rss),
+                            None => String::from("N/A"),
+                        }
+                    );
+                    println!(
+                        "\t\t\t\tMax CPU Time: {}",
+                        match process.max_cpu_time {
+                            Some(time) => format!("{}s", time),
+                            None => String::from("N/A"),
+                        }
+                    );
+                    println!(
+                        "\t\t\t\tPidfile: {}",
+                        process.pidfile.clone().unwrap_or(String::from("N/A"))
+                    );
+                }
+
+                if let Some(disk) = &check.disk {
+                    let fmt_opt = |val: Option<String>| val.unwrap_or(String::from("N/A"));
+
+                    println!("\t\t\tDisk Settings:");
+                    println!("\t\t\t\tMounts: {}", disk.mounts.join(", "));
+                    println!(
+                        "\t\t\t\tUsed % (Warn/Fail): {}/{}",
+                        fmt_opt(disk.warn_used_pct.map(|pct| format!("{}%", pct))),
+                        fmt_opt(disk.fail_used_pct.map(|pct| format!("{}%", pct)))
+                    );
+                    println!(
+                        "\t\t\t\tFree (Warn/Fail): {}/{}",
+                        fmt_opt(disk.warn_free_mb.map(|mb| format!("{}MB", mb))),
+                        fmt_opt(disk.fail_free_mb.map(|mb| format!("{}MB", mb)))
+                    );
+                    println!(
+                        "\t\t\t\tInodes % (Warn/Fail): {}/{}",
+                        fmt_opt(disk.warn_inodes_pct.map(|pct| format!("{}%", pct))),
+                        fmt_opt(disk.fail_inodes_pct.map(|pct| format!("{}%", pct)))
+                    );
+                }
+
+                if let Some(system) = &check.system {
+                    let fmt_opt = |val: Option<f64>| match val {
+                        Some(val) => val.to_string(),
+                        None => String::from("N/A"),
+                    };
+
+                    println!("\t\t\tSystem Settings:");
+                    println!("\t\t\t\tLoad Avg: {}m", system.load_avg);
+                    println!(
+                        "\t\t\t\tLoad Per Core (Warn/Fail): {}/{}",
+                        fmt_opt(system.warn_load_per_core),
+                        fmt_opt(system.fail_load_per_core)
+                    );
+                    println!(
+                        "\t\t\t\tMemory Available % (Warn/Fail): {}/{}",
+                        fmt_opt(system.warn_mem_avail_pct),
+                        fmt_opt(system.fail_mem_avail_pct)
+                    );
+                    println!(
+                        "\t\t\t\tSwap Used % (Warn/Fail): {}/{}",
+                        fmt_opt(system.warn_swap_used_pct),
+                        fmt_opt(system.fail_swap_used_pct)
+                    );
+                    println!("\t\t\t\tPressure Avg: {}s", system.pressure_avg);
+                    println!(
+                        "\t\t\t\tCPU Pressure (Warn/Fail): {}/{}",
+                        fmt_opt(system.warn_cpu_pressure),
+                        fmt_opt(system.fail_cpu_pressure)
+                    );
+                    println!(
+                        "\t\t\t\tMemory Pressure (Warn/Fail): {}/{}",
+                        fmt_opt(system.warn_mem_pressure),
+                        fmt_opt(system.fail_mem_pressure)
+                    );
+                    println!(
+                        "\t\t\t\tIO Pressure (Warn/Fail): {}/{}",
+                        fmt_opt(system.warn_io_pressure),
+                        fmt_opt(system.fail_io_pressure)
+                    );
+                }
+
+                if let Some(file) = &check.file {
+                    let fmt_opt = |val: Option<String>| val.unwrap_or(String::from("N/A"));
+
+                    println!("\t\t\tFile Settings:");
+                    println!("\t\t\t\tPath: {}", file.path);
+                    println!(
+                        "\t\t\t\tMax Age: {}",
+                        fmt_opt(file.max_age.map(|secs| format!("{} secs", secs)))
+                    );
+                    println!(
+                        "\t\t\t\tSize (Min/Max): {}/{}",
+                        fmt_opt(file.min_size.map(|bytes| format!("{} bytes", bytes))),
+                        fmt_opt(file.max_size.map(|bytes| format!("{} bytes", bytes)))
+                    );
+                }
+
+                if let Some(http_flow) = &check.http_flow {
+                    println!("\t\t\tHTTP Flow Settings:");
+
+                    for (i, step) in http_flow.steps.iter().enumerate() {
+                        println!(
+                            "\t\t\t\tStep {} => {} {}",
+                            step.name.clone().unwrap_or((i + 1).to_string()),
+                            step.http.method,
+                            step.http.url
+                        );
+
+                        for (var, capture) in step.capture.iter() {
+                            println!("\t\t\t\t\tCapture {} => {}", var, capture);
+                        }
+                    }
+                }
+
+                if let Some(redis) = &check.redis {
+                    let fmt_opt = |val: Option<u64>| match val {
+                        Some(val) => val.to_string(),
+                        None => String::from("N/A"),
+                    };
+
+                    println!("\t\t\tRedis Settings:");
+                    println!("\t\t\t\tHost: {}", redis.host);
+                    println!("\t\t\t\tPort: {}", redis.port);
+                    println!("\t\t\t\tTimeout: {}", redis.timeout);
+                    println!(
+                        "\t\t\t\tUsername: {}",
+                        redis.username.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!(
+                        "\t\t\t\tPassword: {}",
+                        match redis.password {
+                            Some(_) => "********",
+                            None => "N/A",
+                        }
+                    );
+                    println!(
+                        "\t\t\t\tRole: {}",
+                        redis.role.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!("\t\t\t\tMin Replicas: {}", fmt_opt(redis.min_replicas));
+                    println!(
+                        "\t\t\t\tUsed Memory MB (Warn/Fail): {}/{}",
+                        fmt_opt(redis.warn_used_memory_mb),
+                        fmt_opt(redis.fail_used_memory_mb)
+                    );
+                    println!(
+                        "\t\t\t\tBlocked Clients (Warn/Fail): {}/{}",
+                        fmt_opt(redis.warn_blocked_clients),
+                        fmt_opt(redis.fail_blocked_clients)
+                    );
+                }
+
+                if let Some(postgres) = &check.postgres {
+                    let fmt_opt = |val: Option<f64>| match val {
+                        Some(val) => val.to_string(),
+                        None => String::from("N/A"),
+                    };
+
+                    println!("\t\t\tPostgres Settings:");
+                    println!("\t\t\t\tHost: {}", postgres.host);
+                    println!("\t\t\t\tPort: {}", postgres.port);
+                    println!("\t\t\t\tTimeout: {}", postgres.timeout);
+                    println!("\t\t\t\tUser: {}", postgres.user);
+                    println!(
+                        "\t\t\t\tPassword: {}",
+                        match postgres.password {
+                            Some(_) => "********",
+                            None => "N/A",
+                        }
+                    );
+                    println!(
+                        "\t\t\t\tDatabase: {}",
+                        postgres.database.clone().unwrap_or(postgres.user.clone())
+                    );
+                    println!("\t\t\t\tTLS Mode: {}", postgres.tls_mode);
+
+                    if let Some(ca_file) = &postgres.ca_file {
+                        println!("\t\t\t\tCA File: {}", ca_file);
+                    }
+
+                    if let Some(client_cert) = &postgres.client_cert {
+                        println!("\t\t\t\tClient Cert: {}", client_cert);
+                    }
+
+                    if let Some(client_key) = &postgres.client_key {
+                        println!("\t\t\t\tClient Key: {}", client_key);
+                    }
+
+                    println!("\t\t\t\tQuery: {}", postgres.query);
+                    println!(
+                        "\t\t\t\tExpect: {}",
+                        postgres.expect.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!(
+                        "\t\t\t\tMin (Warn/Fail): {}/{}",
+                        fmt_opt(postgres.warn_min),
+                        fmt_opt(postgres.fail_min)
+                    );
+                    println!(
+                        "\t\t\t\tMax (Warn/Fail): {}/{}",
+                        fmt_opt(postgres.warn_max),
+                        fmt_opt(postgres.fail_max)
+                    );
+                }
+
+                if let Some(mysql) = &check.mysql {
+                    println!("\t\t\tMySQL Settings:");
+                    println!("\t\t\t\tHost: {}", mysql.host);
+                    println!("\t\t\t\tPort: {}", mysql.port);
+                    println!("\t\t\t\tTimeout: {}", mysql.timeout);
+                    println!("\t\t\t\tUser: {}", mysql.user);
+                    println!(
+                        "\t\t\t\tPassword: {}",
+                        match mysql.password {
+                            Some(_) => "********",
+                            None => "N/A",
+                        }
+                    );
+                    println!(
+                        "\t\t\t\tDatabase: {}",
+                        mysql.database.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!(
+                        "\t\t\t\tQuery: {}",
+                        mysql.query.clone().unwrap_or(String::from("N/A"))
+                    );
+
+                    if !mysql.expect.is_empty() {
+                        println!("\t\t\t\tExpect:");
+
+                        for expr in mysql.expect.iter() {
+                            println!("\t\t\t\t\t{}", expr);
+                        }
+                    }
+                }
+
+                if let Some(smtp) = &check.smtp {
+                    println!("\t\t\tSMTP Settings:");
+                    println!("\t\t\t\tHost: {}", smtp.host);
+                    println!("\t\t\t\tPort: {}", smtp.port);
+                    println!("\t\t\t\tTimeout: {}", smtp.timeout);
+                    println!("\t\t\t\tEHLO Name: {}", smtp.ehlo_name);
+
+                    if !smtp.expect_extensions.is_empty() {
+                        println!(
+                            "\t\t\t\tExpect Extensions: {}",
+                            smtp.expect_extensions.join(", ")
+                        );
+                    }
+
+                    println!("\t\t\t\tSTARTTLS => {}", smtp.starttls);
+
+                    if smtp.starttls {
+                        println!(
+                            "\t\t\t\tSNI: {}",
+                            smtp.sni.clone().unwrap_or(String::from("N/A"))
+                        );
+                        println!("\t\t\t\tIs Insecure => {}", smtp.is_insecure);
+                        println!(
+                            "\t\t\t\tCA File: {}",
+                            smtp.ca_file.clone().unwrap_or(String::from("N/A"))
+                        );
+                        println!("\t\t\t\tWarn Days: {}", smtp.warn_days);
+                        println!("\t\t\t\tFail Days: {}", smtp.fail_days);
+                    }
+
+                    println!(
+                        "\t\t\t\tUsername: {}",
+                        smtp.username.clone().unwrap_or(String::from("N/A"))
+                    );
+                    println!(
+                        "\t\t\t\tPassword: {}",
+                        match smtp.password {
+                            Some(_) => "********",
+                            None => "N/A",
+                        }
+                    );
+                    println!(
+                        "\t\t\t\tAllow Plaintext Auth => {}",
+                        smtp.allow_plaintext_auth
+                    );
+                }
+
+                if let Some(grpc) = &check.grpc {
+                    println!("\t\t\tgRPC Settings:");
+                    println!("\t\t\t\tHost: {}", grpc.host);
+                    println!("\t\t\t\tPort: {}", grpc.port);
+                    println!("\t\t\t\tTimeout: {}", grpc.timeout);
+                    println!(
+                        "\t\t\t\tService: {}",
+                        match grpc.service.is_empty() {
+                            true => String::from("N/A"),
+                            false => grpc.service.clone(),
+                        }
+                    );
+                    println!("\t\t\t\tTLS => {}", grpc.tls);
+                    println!("\t\t\t\tIs Insecure => {}", grpc.is_insecure);
+
+                    if let Some(ca_file) = &grpc.ca_file {
+                        println!("\t\t\t\tCA File: {}", ca_file);
+                    }
+
+                    if let Some(client_cert) = &grpc.client_cert {
+                        println!("\t\t\t\tClient Cert: {}", client_cert);
+                    }
+
+                    if let Some(client_key) = &grpc.client_key {
+                        println!("\t\t\t\tClient Key: {}", client_key);
+                    }
+
+                    if !grpc.metadata.is_empty() {
+                        println!("\t\t\t\tMetadata:");
+
+                        // Metadata often carries tokens, so only the keys are shown.
+                        for key in grpc.metadata.keys() {
+                            println!("\t\t\t\t\t{}: ********", key);
+                        }
+                    }
                 }
 
                 if let Some(alert) = &service.alert_pass {
@@ -108,7 +672,7 @@ impl Config {
                         );
 
                         if let Some(headers) = &http.headers
-                            && headers.len() > 0
+                            && !headers.is_empty()
                         {
                             println!("\t\t\t\tHeaders:");
                             for (key, val) in headers {
@@ -117,6 +681,34 @@ impl Config {
                         }
 
                         println!("\t\t\t\tIs Insecure => {}", http.is_insecure);
+
+                        if let Some(auth) = &http.auth {
+                            println!("\t\t\t\tAuth: {}", auth);
+                        }
+
+                        if let Some(ca_file) = &http.ca_file {
+                            println!("\t\t\t\tCA File: {}", ca_file);
+                        }
+
+                        if let Some(client_cert) = &http.client_cert {
+                            println!("\t\t\t\tClient Cert: {}", client_cert);
+                        }
+
+                        if let Some(client_key) = &http.client_key {
+                            println!("\t\t\t\tClient Key: {}", client_key);
+                        }
+
+                        if !http.pins.is_empty() {
+                            println!("\t\t\t\tPins: {}", http.pins.join(", "));
+                        }
+
+                        if let Some(proxy) = &http.proxy {
+                            println!("\t\t\t\tProxy: {}", proxy.masked_url());
+
+                            if !proxy.no_proxy.is_empty() {
+                                println!("\t\t\t\tNo Proxy: {}", proxy.no_proxy.join(", "));
+                            }
+                        }
                     }
                 }
 
@@ -144,7 +736,7 @@ impl Config {
                         );
 
                         if let Some(headers) = &http.headers
-                            && headers.len() > 0
+                            && !headers.is_empty()
                         {
                             println!("\t\t\t\tHeaders:");
                             for (key, val) in headers {
@@ -153,6 +745,34 @@ impl Config {
                         }
 
                         println!("\t\t\t\tIs Insecure => {}", http.is_insecure);
+
+                        if let Some(auth) = &http.auth {
+                            println!("\t\t\t\tAuth: {}", auth);
+                        }
+
+                        if let Some(ca_file) = &http.ca_file {
+                            println!("\t\t\t\tCA File: {}", ca_file);
+                        }
+
+                        if let Some(client_cert) = &http.client_cert {
+                            println!("\t\t\t\tClient Cert: {}", client_cert);
+                        }
+
+                        if let Some(client_key) = &http.client_key {
+                            println!("\t\t\t\tClient Key: {}", client_key);
+                        }
+
+                        if !http.pins.is_empty() {
+                            println!("\t\t\t\tPins: {}", http.pins.join(", "));
+                        }
+
+                        if let Some(proxy) = &http.proxy {
+                            println!("\t\t\t\tProxy: {}", proxy.masked_url());
+
+                            if !proxy.no_proxy.is_empty() {
+                                println!("\t\t\t\tNo Proxy: {}", proxy.no_proxy.join(", "));
+                            }
+                        }
                     }
                 }
             }
@@ -51,18 +51,15 @@ impl Logger {
         let log_msg = format!("[{}] {}", level, message);
         let log_msg_date = format!("[{}] {}", date_str, log_msg);
 
-        match &self.log_file {
-            Some(file_path) => {
-                // Append log message to the specified file.
+        if let Some(file_path) = &self.log_file {
+            // Append log message to the specified file.
 
-                let mut file = OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open(file_path)
-                    .expect("Unable to open log file");
-                writeln!(file, "{}", log_msg_date).expect("Unable to write to log file");
-            }
-            None => {}
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(file_path)
+                .expect("Unable to open log file");
+            writeln!(file, "{}", log_msg_date).expect("Unable to write to log file");
         }
         // If in input mode, we need to clear the current line, print the message, and flush.
         if !skip_input && self.input_mode {
@@ -78,14 +75,15 @@ impl Logger {
     }
 
     fn should_log(&self, level: &LogLevel) -> bool {
-        match (&self.level, level) {
-            (LogLevel::DEBUG, _) => true,
-            (LogLevel::INFO, LogLevel::INFO)
-            | (LogLevel::INFO, LogLevel::WARN)
-            | (LogLevel::INFO, LogLevel::ERROR) => true,
-            (LogLevel::WARN, LogLevel::WARN) | (LogLevel::WARN, LogLevel::ERROR) => true,
-            (LogLevel::ERROR, LogLevel::ERROR) => true,
-            _ => false,
-        }
+        matches!(
+            (&self.level, level),
+            (LogLevel::DEBUG, _)
+                | (LogLevel::INFO, LogLevel::INFO)
+                | (LogLevel::INFO, LogLevel::WARN)
+                | (LogLevel::INFO, LogLevel::ERROR)
+                | (LogLevel::WARN, LogLevel::WARN)
+                | (LogLevel::WARN, LogLevel::ERROR)
+                | (LogLevel::ERROR, LogLevel::ERROR)
+        )
     }
 }
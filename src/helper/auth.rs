@@ -0,0 +1,239 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use reqwest::{Client, Proxy, RequestBuilder, Response, StatusCode};
+use tokio::sync::Mutex;
+
+use crate::config::{HttpAuthConfig, HttpAuthType};
+use crate::helper::http_tls;
+use crate::helper::tls::ClientTls;
+
+// How long before a token's expiry we consider it stale.
+const OAUTH2_EXPIRY_SKEW: Duration = Duration::from_secs(30);
+
+#[derive(Clone)]
+pub enum HttpAuth {
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+    Bearer(String),
+    OAuth2(OAuth2Client),
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+
+    // Some token endpoints don't tell us when the token expires.
+    // Those tokens are used until they're rejected.
+    expires: Option<Instant>,
+}
+
+#[derive(Clone)]
+pub struct OAuth2Client {
+    pub token_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scope: Option<String>,
+
+    // Shared between clones so every run of a check reuses the same token.
+    cache: Arc<Mutex<Option<CachedToken>>>,
+}
+
+// The settings an OAuth2 token request shares with the check or alert using it.
+// The token endpoint is usually a different server, so pins, client certs and
+// pinned addresses are left out.
+pub struct TokenClient<'a> {
+    pub proxy: Option<&'a Proxy>,
+    pub is_insecure: bool,
+    pub ca_file: Option<&'a str>,
+}
+
+impl TokenClient<'_> {
+    fn build(&self) -> Result<Client> {
+        let mut builder = Client::builder()
+            .danger_accept_invalid_certs(self.is_insecure)
+            .danger_accept_invalid_hostnames(self.is_insecure);
+
+        if let Some(ca_file) = self.ca_file {
+            let tls = ClientTls {
+                ca_file: Some(ca_file.to_string()),
+                ..Default::default()
+            };
+
+            builder = builder.tls_backend_preconfigured(http_tls(&tls, self.is_insecure)?);
+        }
+
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+// Checks are printed with Debug when listing services, so keep the secrets out of it.
+impl fmt::Debug for HttpAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpAuth::Basic { username, password } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &password.as_ref().map(|_| "********"))
+                .finish(),
+            HttpAuth::Bearer(_) => f.debug_tuple("Bearer").field(&"********").finish(),
+            HttpAuth::OAuth2(oauth) => f.debug_tuple("OAuth2").field(oauth).finish(),
+        }
+    }
+}
+
+impl fmt::Debug for OAuth2Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2Client")
+            .field("token_url", &self.token_url)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"********")
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+impl HttpAuth {
+    pub fn from_config(cfg: &HttpAuthConfig) -> Result<HttpAuth> {
+        let require = |val: &Option<String>, name: &str| match val {
+            Some(val) => Ok(val.clone()),
+            None => Err(anyhow!("{} auth requires '{}'", cfg.auth_type, name)),
+        };
+
+        Ok(match cfg.auth_type {
+            HttpAuthType::BASIC => HttpAuth::Basic {
+                username: require(&cfg.username, "username")?,
+                password: cfg.password.clone(),
+            },
+            HttpAuthType::BEARER => HttpAuth::Bearer(require(&cfg.token, "token")?),
+            HttpAuthType::OAUTH2 => HttpAuth::OAuth2(OAuth2Client {
+                token_url: require(&cfg.token_url, "token_url")?,
+                client_id: require(&cfg.client_id, "client_id")?,
+                client_secret: require(&cfg.client_secret, "client_secret")?,
+                scope: cfg.scope.clone(),
+                cache: Arc::new(Mutex::new(None)),
+            }),
+        })
+    }
+
+    // Sends the request with our credentials attached.
+    // If an OAuth2 token is rejected, a new one is fetched and the request is sent once more.
+    pub async fn send(
+        &self,
+        token_cl: &TokenClient<'_>,
+        req: RequestBuilder,
+        timeout: u64,
+    ) -> Result<reqwest::Result<Response>> {
+        let HttpAuth::OAuth2(oauth) = self else {
+            return Ok(self.apply(token_cl, req, timeout).await?.send().await);
+        };
+
+        let retry = req.try_clone();
+
+        let res = self.apply(token_cl, req, timeout).await?.send().await;
+
+        if let Ok(res) = &res
+            && res.status() == StatusCode::UNAUTHORIZED
+            && let Some(retry) = retry
+        {
+            oauth.invalidate().await;
+
+            return Ok(self.apply(token_cl, retry, timeout).await?.send().await);
+        }
+
+        Ok(res)
+    }
+
+    async fn apply(
+        &self,
+        token_cl: &TokenClient<'_>,
+        req: RequestBuilder,
+        timeout: u64,
+    ) -> Result<RequestBuilder> {
+        Ok(match self {
+            HttpAuth::Basic { username, password } => req.basic_auth(username, password.as_ref()),
+            HttpAuth::Bearer(token) => req.bearer_auth(token),
+            HttpAuth::OAuth2(oauth) => req.bearer_auth(oauth.token(token_cl, timeout).await?),
+        })
+    }
+}
+
+impl OAuth2Client {
+    pub async fn token(&self, token_cl: &TokenClient<'_>, timeout: u64) -> Result<String> {
+        // Holding the lock while fetching keeps concurrent runs from requesting multiple tokens.
+        let mut cache = self.cache.lock().await;
+
+        if let Some(cached) = cache.as_ref()
+            && cached
+                .expires
+                .is_none_or(|expires| expires > Instant::now())
+        {
+            return Ok(cached.token.clone());
+        }
+
+        let mut form: Vec<(&str, &str)> = vec![("grant_type", "client_credentials")];
+
+        if let Some(scope) = &self.scope {
+            form.push(("scope", scope));
+        }
+
+        let mut req = token_cl
+            .build()?
+            .post(&self.token_url)
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .form(&form);
+
+        if timeout > 0 {
+            req = req.timeout(Duration::from_secs(timeout));
+        }
+
+        let res = match req.send().await {
+            Ok(res) => res,
+            Err(e) => return Err(anyhow!("OAuth2 token request failed: {}", e)),
+        };
+
+        let status_code = res.status().as_u16();
+
+        if !res.status().is_success() {
+            return Err(anyhow!(
+                "OAuth2 token request failed with status code: {}",
+                status_code
+            ));
+        }
+
+        let body: serde_json::Value = match res.json().await {
+            Ok(body) => body,
+            Err(e) => return Err(anyhow!("Invalid OAuth2 token response: {}", e)),
+        };
+
+        let Some(token) = body.get("access_token").and_then(|token| token.as_str()) else {
+            return Err(anyhow!("OAuth2 token response is missing 'access_token'"));
+        };
+
+        let expires = body
+            .get("expires_in")
+            .and_then(|secs| secs.as_u64())
+            .map(|secs| {
+                Instant::now() + Duration::from_secs(secs).saturating_sub(OAUTH2_EXPIRY_SKEW)
+            });
+
+        *cache = Some(CachedToken {
+            token: token.to_string(),
+            expires,
+        });
+
+        Ok(token.to_string())
+    }
+
+    pub async fn invalidate(&self) {
+        *self.cache.lock().await = None;
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+use anyhow::{Result, anyhow};
+use regex::Regex;
+
+// Where to pull a value from in a HTTP response so later requests can use it.
+// Written as `json:/pointer`, `regex:expression`, `cookie:name`, or `header:name`.
+#[derive(Debug, Clone)]
+pub enum HttpCapture {
+    Json(String),
+    Regex(Regex),
+    Cookie(String),
+    Header(String),
+}
+
+impl fmt::Display for HttpCapture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpCapture::Json(pointer) => write!(f, "json:{}", pointer),
+            HttpCapture::Regex(re) => write!(f, "regex:{}", re.as_str()),
+            HttpCapture::Cookie(name) => write!(f, "cookie:{}", name),
+            HttpCapture::Header(name) => write!(f, "header:{}", name),
+        }
+    }
+}
+
+impl HttpCapture {
+    pub fn parse(expr: &str) -> Result<HttpCapture> {
+        let Some((kind, val)) = expr.split_once(':') else {
+            return Err(anyhow!(
+                "Invalid capture '{}': expected json:, regex:, cookie:, or header:",
+                expr
+            ));
+        };
+
+        if val.is_empty() {
+            return Err(anyhow!("Invalid capture '{}': missing value", expr));
+        }
+
+        match kind.to_lowercase().as_str() {
+            "json" if val.starts_with('/') => Ok(HttpCapture::Json(val.to_string())),
+            "json" => Err(anyhow!(
+                "Invalid capture '{}': pointer must start with '/'",
+                expr
+            )),
+            "regex" => Ok(HttpCapture::Regex(Regex::new(val)?)),
+            "cookie" => Ok(HttpCapture::Cookie(val.to_string())),
+            "header" => Ok(HttpCapture::Header(val.to_string())),
+            _ => Err(anyhow!(
+                "Invalid capture '{}': expected json:, regex:, cookie:, or header:",
+                expr
+            )),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use anyhow::Result;
+use regex::Regex;
+
+// The subject of an assertion and the operator and value it's compared with (if any).
+pub type CmpExpr = (String, Option<(CmpOp, String)>);
+
+// A comparison operator used by assertions (e.g. JSON pointers and MySQL columns).
+#[derive(Debug, Clone, PartialEq)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CmpOp {
+    // Splits `<subject> [<op> <value>]` into its parts.
+    // The subject can't contain whitespace or operator characters, but it may be empty.
+    pub fn split(expr: &str) -> Result<Option<CmpExpr>> {
+        let re = Regex::new(r"^\s*([^\s=!<>]*)\s*(?:(==|!=|<=|>=|<|>)\s*(.+?))?\s*$")?;
+
+        let Some(caps) = re.captures(expr) else {
+            return Ok(None);
+        };
+
+        let cmp = match (caps.get(2), caps.get(3)) {
+            (Some(op), Some(val)) => {
+                let op = match op.as_str() {
+                    "==" => CmpOp::Eq,
+                    "!=" => CmpOp::Ne,
+                    "<" => CmpOp::Lt,
+                    "<=" => CmpOp::Le,
+                    ">" => CmpOp::Gt,
+                    _ => CmpOp::Ge,
+                };
+
+                Some((op, val.as_str().to_string()))
+            }
+            _ => None,
+        };
+
+        Ok(Some((caps[1].to_string(), cmp)))
+    }
+
+    // Whether two values ordered as `ord` satisfy the operator.
+    // Values that can't be ordered are only ever unequal.
+    pub fn matches(&self, ord: Option<Ordering>) -> bool {
+        match (self, ord) {
+            (CmpOp::Eq, ord) => ord == Some(Ordering::Equal),
+            (CmpOp::Ne, ord) => ord != Some(Ordering::Equal),
+            (CmpOp::Lt, Some(ord)) => ord == Ordering::Less,
+            (CmpOp::Le, Some(ord)) => ord != Ordering::Greater,
+            (CmpOp::Gt, Some(ord)) => ord == Ordering::Greater,
+            (CmpOp::Ge, Some(ord)) => ord != Ordering::Less,
+            (_, None) => false,
+        }
+    }
+}
+
+impl fmt::Display for CmpOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CmpOp::Eq => write!(f, "=="),
+            CmpOp::Ne => write!(f, "!="),
+            CmpOp::Lt => write!(f, "<"),
+            CmpOp::Le => write!(f, "<="),
+            CmpOp::Gt => write!(f, ">"),
+            CmpOp::Ge => write!(f, ">="),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmp(op: CmpOp, val: &str) -> Option<(CmpOp, String)> {
+        Some((op, val.to_string()))
+    }
+
+    #[test]
+    fn splits_expressions() {
+        let split = |expr| CmpOp::split(expr).unwrap();
+
+        assert_eq!(
+            split("/a == 1"),
+            Some((String::from("/a"), cmp(CmpOp::Eq, "1")))
+        );
+        assert_eq!(
+            split("/a!=x"),
+            Some((String::from("/a"), cmp(CmpOp::Ne, "x")))
+        );
+        assert_eq!(
+            split("n <= 5"),
+            Some((String::from("n"), cmp(CmpOp::Le, "5")))
+        );
+        assert_eq!(split("n>5"), Some((String::from("n"), cmp(CmpOp::Gt, "5"))));
+
+        // Values keep inner whitespace but not the surrounding whitespace.
+        assert_eq!(
+            split("  /a <  two words  "),
+            Some((String::from("/a"), cmp(CmpOp::Lt, "two words")))
+        );
+
+        // No operator, or nothing at all.
+        assert_eq!(split(" /a "), Some((String::from("/a"), None)));
+        assert_eq!(split(""), Some((String::new(), None)));
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        // An operator without a value.
+        assert_eq!(CmpOp::split("/a ==").unwrap(), None);
+
+        // A single `=` isn't an operator.
+        assert_eq!(CmpOp::split("/a = 1").unwrap(), None);
+
+        // Subjects can't contain whitespace.
+        assert_eq!(CmpOp::split("/a b == 1").unwrap(), None);
+    }
+
+    #[test]
+    fn matches_orderings() {
+        assert!(CmpOp::Eq.matches(Some(Ordering::Equal)));
+        assert!(CmpOp::Ge.matches(Some(Ordering::Equal)));
+        assert!(!CmpOp::Lt.matches(Some(Ordering::Equal)));
+
+        // Values that can't be ordered are only unequal.
+        assert!(CmpOp::Ne.matches(None));
+        assert!(!CmpOp::Eq.matches(None));
+        assert!(!CmpOp::Gt.matches(None));
+    }
+}
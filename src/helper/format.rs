@@ -1,9 +1,59 @@
 use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+// Replaces placeholders in a single pass so values that look like placeholders are left alone.
 pub fn format_contents(contents: &str, fmt: HashMap<String, String>) -> String {
-    let mut formatted = String::from(contents);
-    for (key, value) in fmt {
-        formatted = formatted.replace(&key, &value);
+    let mut formatted = String::new();
+    let mut rest = contents;
+
+    while let Some(ch) = rest.chars().next() {
+        match fmt
+            .iter()
+            .find(|(key, _)| !key.is_empty() && rest.starts_with(key.as_str()))
+        {
+            Some((key, value)) => {
+                formatted.push_str(value);
+                rest = &rest[key.len()..];
+            }
+            None => {
+                formatted.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
     }
+
     formatted
 }
+
+// Placeholders that are filled in each time a request body is sent.
+pub fn body_vars(service: &str) -> HashMap<String, String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|dur| dur.as_secs())
+        .unwrap_or(0);
+
+    HashMap::from([
+        (String::from("{timestamp}"), timestamp.to_string()),
+        (String::from("{uuid}"), uuid::Uuid::new_v4().to_string()),
+        (String::from("{service}"), service.to_string()),
+    ])
+}
+
+// Percent-encodes everything except the unreserved characters so a value can go anywhere in a URL.
+pub fn percent_encode(val: &str) -> String {
+    val.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+// Escapes a value so it can go inside a JSON string.
+pub fn json_escape(val: &str) -> String {
+    let quoted = serde_json::Value::from(val).to_string();
+
+    quoted[1..quoted.len() - 1].to_string()
+}
@@ -0,0 +1,39 @@
+use anyhow::{Result, anyhow};
+
+pub fn decode_hex(contents: &str) -> Result<Vec<u8>> {
+    // Allow whitespace between bytes for readability (e.g. "ff ff ff ff").
+    let contents: String = contents.chars().filter(|c| !c.is_whitespace()).collect();
+
+    // from_str_radix would also accept a sign (e.g. "+f"), so check the digits ourselves.
+    if !contents.chars().all(|c| c.is_ascii_hexdigit()) || !contents.len().is_multiple_of(2) {
+        return Err(anyhow!("Invalid hex string: {}", contents));
+    }
+
+    (0..contents.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&contents[i..i + 2], 16)
+                .map_err(|e| anyhow!("Invalid hex string '{}': {}", contents, e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_hex() {
+        assert_eq!(decode_hex("ff000a").unwrap(), [0xff, 0x00, 0x0a]);
+        assert_eq!(decode_hex("FF 00\n0A").unwrap(), [0xff, 0x00, 0x0a]);
+        assert!(decode_hex("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        assert!(decode_hex("abc").is_err());
+        assert!(decode_hex("zz").is_err());
+        assert!(decode_hex("+f").is_err());
+        assert!(decode_hex("éa").is_err());
+    }
+}
@@ -1,4 +1,15 @@
+use std::collections::HashMap;
 use std::fmt;
+use std::fs;
+
+use anyhow::{Result, anyhow};
+use reqwest::{NoProxy, Proxy};
+use rustls::ClientConfig;
+
+use crate::config::HttpProxyConfig;
+
+use crate::helper::format::{body_vars, format_contents};
+use crate::helper::tls::ClientTls;
 
 #[derive(Debug, Clone)]
 pub enum HttpMethod {
@@ -24,7 +35,7 @@ impl fmt::Display for HttpMethod {
 }
 
 impl HttpMethod {
-    pub fn from_str(method: &str) -> HttpMethod {
+    pub fn from_string(method: &str) -> HttpMethod {
         match method.to_lowercase().as_str() {
             "get" => HttpMethod::GET,
             "post" => HttpMethod::POST,
@@ -35,3 +46,79 @@ impl HttpMethod {
         }
     }
 }
+
+// Builds the rustls config for a HTTP client.
+// reqwest uses a preconfigured config as-is, so we need to offer HTTP/2 ourselves.
+pub fn http_tls(tls: &ClientTls, is_insecure: bool) -> Result<ClientConfig> {
+    let mut cfg = tls.client_config(is_insecure)?;
+
+    cfg.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(cfg)
+}
+
+// Loads a request body (reading it from disk if it's a file) and fills in its placeholders.
+// Files are read every time so changes are picked up without a restart.
+pub fn load_body(
+    body: &str,
+    body_is_file: bool,
+    service: &str,
+    extra_vars: HashMap<String, String>,
+) -> Result<String> {
+    let contents = match body_is_file {
+        true => match fs::read_to_string(body) {
+            Ok(contents) => contents,
+            Err(e) => return Err(anyhow!("Failed to read body from file {}: {}", body, e)),
+        },
+        false => body.to_string(),
+    };
+
+    let mut vars = body_vars(service);
+
+    vars.extend(extra_vars);
+
+    Ok(format_contents(&contents, vars))
+}
+
+// A proxy along with the URL to show for it.
+// reqwest's Debug prints the proxy URL with its credentials, so ours shows a masked one instead.
+#[derive(Clone)]
+pub struct HttpProxy {
+    pub proxy: Proxy,
+    url: String,
+}
+
+impl fmt::Debug for HttpProxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpProxy").field("url", &self.url).finish()
+    }
+}
+
+pub fn proxy_from_config(cfg: &HttpProxyConfig) -> Result<HttpProxy> {
+    let scheme = cfg
+        .url
+        .split_once("://")
+        .map(|(scheme, _)| scheme.to_lowercase());
+
+    if !matches!(
+        scheme.as_deref(),
+        Some("http" | "https" | "socks4" | "socks4a" | "socks5" | "socks5h")
+    ) {
+        return Err(anyhow!("Unsupported proxy URL: {}", cfg.masked_url()));
+    }
+
+    let mut proxy = match Proxy::all(&cfg.url) {
+        Ok(proxy) => proxy,
+        Err(e) => return Err(anyhow!("Invalid proxy URL {}: {}", cfg.masked_url(), e)),
+    };
+
+    if let Some(username) = &cfg.username {
+        proxy = proxy.basic_auth(username, cfg.password.as_deref().unwrap_or_default());
+    }
+
+    // Don't fall back to the NO_PROXY environment variable.
+    Ok(HttpProxy {
+        proxy: proxy.no_proxy(NoProxy::from_string(&cfg.no_proxy.join(","))),
+        url: cfg.masked_url(),
+    })
+}
@@ -0,0 +1,137 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use anyhow::{Result, anyhow};
+use serde_json::Value;
+
+use crate::helper::cmp::CmpOp;
+
+// An assertion against a value inside a JSON document (e.g. `/status == "ok"`).
+// If there's no operator, the pointer only has to exist.
+#[derive(Debug, Clone)]
+pub struct JsonAssert {
+    pub pointer: String,
+    pub cmp: Option<(CmpOp, Value)>,
+}
+
+impl JsonAssert {
+    pub fn parse(expr: &str) -> Result<JsonAssert> {
+        let Some((pointer, cmp)) = CmpOp::split(expr)? else {
+            return Err(anyhow!("Invalid JSON assertion: {}", expr));
+        };
+
+        if !pointer.is_empty() && !pointer.starts_with('/') {
+            return Err(anyhow!(
+                "Invalid JSON assertion '{}': pointer must start with '/'",
+                expr
+            ));
+        }
+
+        // Unquoted values that aren't valid JSON are treated as strings.
+        let cmp = cmp.map(|(op, val)| {
+            let val = serde_json::from_str(&val).unwrap_or(Value::String(val));
+
+            (op, val)
+        });
+
+        Ok(JsonAssert { pointer, cmp })
+    }
+
+    // Returns a description of the failure if the document doesn't satisfy the assertion.
+    pub fn eval(&self, doc: &Value) -> Option<String> {
+        let Some(actual) = doc.pointer(&self.pointer) else {
+            return Some(format!("JSON pointer {} not found", self.pointer));
+        };
+
+        let (op, expected) = self.cmp.as_ref()?;
+
+        let ord = match Self::equals(actual, expected) {
+            true => Some(Ordering::Equal),
+            false => Self::compare(actual, expected),
+        };
+
+        let ok = op.matches(ord);
+
+        match ok {
+            true => None,
+            false => Some(format!(
+                "JSON {} is {} (expected {} {})",
+                self.pointer, actual, op, expected
+            )),
+        }
+    }
+
+    fn equals(a: &Value, b: &Value) -> bool {
+        // Compare numbers by value so 1 and 1.0 are equal.
+        match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => a == b,
+            _ => a == b,
+        }
+    }
+
+    fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+        match (a, b) {
+            (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+            (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for JsonAssert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.cmp {
+            Some((op, val)) => write!(f, "{} {} {}", self.pointer, op, val),
+            None => write!(f, "{}", self.pointer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_assertions() {
+        let assert = JsonAssert::parse("/status == \"ok\"").unwrap();
+
+        assert_eq!(assert.pointer, "/status");
+        assert_eq!(assert.cmp, Some((CmpOp::Eq, json!("ok"))));
+
+        let assert = JsonAssert::parse("/items/0/count >= 2.5").unwrap();
+
+        assert_eq!(assert.cmp, Some((CmpOp::Ge, json!(2.5))));
+
+        // Unquoted values that aren't JSON are strings.
+        let assert = JsonAssert::parse("/status != degraded").unwrap();
+
+        assert_eq!(assert.cmp, Some((CmpOp::Ne, json!("degraded"))));
+
+        // An empty pointer is the whole document.
+        let assert = JsonAssert::parse("").unwrap();
+
+        assert_eq!(assert.pointer, "");
+        assert_eq!(assert.cmp, None);
+    }
+
+    #[test]
+    fn rejects_malformed_assertions() {
+        assert!(JsonAssert::parse("status == 1").is_err());
+        assert!(JsonAssert::parse("/status ==").is_err());
+        assert!(JsonAssert::parse("/status = 1").is_err());
+    }
+
+    #[test]
+    fn evaluates_assertions() {
+        let doc = json!({ "status": "ok", "count": 1 });
+        let eval = |expr| JsonAssert::parse(expr).unwrap().eval(&doc);
+
+        assert_eq!(eval("/status"), None);
+        assert_eq!(eval("/count == 1.0"), None);
+        assert_eq!(eval("/count < 2"), None);
+        assert!(eval("/missing").is_some());
+        assert!(eval("/status > 1").is_some());
+    }
+}
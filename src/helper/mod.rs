@@ -1,5 +1,18 @@
+pub mod auth;
+pub mod capture;
+pub mod cmp;
 pub mod format;
+pub mod hex;
 pub mod http;
+pub mod json;
+pub mod secret;
+pub mod timing;
+pub mod tls;
 
-pub use format::format_contents;
-pub use http::{HTTP_OK_CODES, HttpMethod};
+pub use auth::HttpAuth;
+pub use capture::HttpCapture;
+pub use format::{format_contents, json_escape, percent_encode};
+pub use hex::decode_hex;
+pub use http::{HTTP_OK_CODES, HttpMethod, HttpProxy, http_tls, load_body, proxy_from_config};
+pub use json::JsonAssert;
+pub use secret::Secret;
@@ -0,0 +1,38 @@
+use std::fmt;
+
+// A value (e.g. a password) that must not show up when checks are printed.
+// Checks are listed through Debug, so only Debug is masked; use `expose` to get the value.
+#[derive(Clone, Default, PartialEq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Secret {
+    fn from(val: String) -> Self {
+        Secret(val)
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"********\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_debug_output() {
+        let secret = Secret::from(String::from("hunter2"));
+
+        assert_eq!(format!("{:?}", secret), "\"********\"");
+        assert_eq!(format!("{:?}", Some(secret.clone())), "Some(\"********\")");
+        assert_eq!(secret.expose(), "hunter2");
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use rustls::NamedGroup;
+use rustls::client::Tls13ClientSessionValue;
+use rustls::client::{ClientSessionMemoryCache, ClientSessionStore, Tls12ClientSessionValue};
+use rustls::pki_types::ServerName;
+use tokio::net::lookup_host;
+use tower_layer::Layer;
+use tower_service::Service;
+
+// A breakdown of where a HTTP request spent its time (in milliseconds).
+// Connection phases are only set when a new connection was made for the request.
+#[derive(Debug, Clone, Default)]
+pub struct HttpTimings {
+    pub dns: Option<u32>,
+    pub connect: Option<u32>,
+    pub tls: Option<u32>,
+
+    // From the connection being ready (or the request starting) to the response headers.
+    pub ttfb: u32,
+
+    // Reading the response body.
+    pub body: u32,
+
+    pub total: u32,
+}
+
+impl fmt::Display for HttpTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fmt_opt = |val: Option<u32>| match val {
+            Some(ms) => format!("{}ms", ms),
+            None => String::from("N/A"),
+        };
+
+        write!(
+            f,
+            "DNS {}, Connect {}, TLS {}, TTFB {}ms, Body {}ms, Total {}ms",
+            fmt_opt(self.dns),
+            fmt_opt(self.connect),
+            fmt_opt(self.tls),
+            self.ttfb,
+            self.body,
+            self.total
+        )
+    }
+}
+
+// Points in time recorded by the hooks below while a request runs.
+// Each new connection overwrites the previous one's marks, so the last connection wins.
+#[derive(Debug, Default)]
+struct Marks {
+    dns_start: Option<Instant>,
+    dns_end: Option<Instant>,
+
+    conn_start: Option<Instant>,
+    tls_start: Option<Instant>,
+    conn_end: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TimingMarks {
+    marks: Arc<Mutex<Marks>>,
+}
+
+impl TimingMarks {
+    pub fn resolver(&self) -> Arc<TimedResolver> {
+        Arc::new(TimedResolver {
+            marks: self.clone(),
+        })
+    }
+
+    pub fn connect_layer(&self) -> TimedConnectLayer {
+        TimedConnectLayer {
+            marks: self.clone(),
+        }
+    }
+
+    pub fn session_store(&self) -> Arc<TimedSessionStore> {
+        Arc::new(TimedSessionStore {
+            inner: ClientSessionMemoryCache::new(32),
+            marks: self.clone(),
+        })
+    }
+
+    fn mark(&self, f: impl FnOnce(&mut Marks)) {
+        f(&mut self.marks.lock().unwrap());
+    }
+
+    pub fn timings(&self, start: Instant, headers: Instant, end: Instant) -> HttpTimings {
+        let marks = self.marks.lock().unwrap();
+
+        let ms = |from: Instant, to: Instant| to.saturating_duration_since(from).as_millis() as u32;
+
+        // A connection opened before the request started wasn't made for it, so its phases don't count.
+        let fresh = marks
+            .conn_start
+            .zip(marks.conn_end)
+            .filter(|(conn_start, _)| *conn_start >= start);
+
+        let mut timings = HttpTimings {
+            ttfb: ms(start, headers),
+            body: ms(headers, end),
+            total: ms(start, end),
+            ..Default::default()
+        };
+
+        if let Some((conn_start, conn_end)) = fresh {
+            let dns = marks
+                .dns_start
+                .zip(marks.dns_end)
+                .filter(|(dns_start, _)| *dns_start >= conn_start);
+
+            let tcp_start = dns.map(|(_, dns_end)| dns_end).unwrap_or(conn_start);
+            let tls_start = marks.tls_start.filter(|tls_start| *tls_start >= tcp_start);
+
+            timings.dns = dns.map(|(dns_start, dns_end)| ms(dns_start, dns_end));
+            timings.connect = Some(ms(tcp_start, tls_start.unwrap_or(conn_end)));
+            timings.tls = tls_start.map(|tls_start| ms(tls_start, conn_end));
+            timings.ttfb = ms(conn_end.max(start), headers);
+        }
+
+        timings
+    }
+}
+
+// Resolves hostnames the same way as the system resolver while timing the lookup.
+#[derive(Debug)]
+pub struct TimedResolver {
+    marks: TimingMarks,
+}
+
+impl Resolve for TimedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let marks = self.marks.clone();
+
+        Box::pin(async move {
+            marks.mark(|m| {
+                m.dns_start = Some(Instant::now());
+                m.dns_end = None;
+            });
+
+            let addrs: Vec<SocketAddr> = lookup_host((name.as_str(), 0)).await?.collect();
+
+            marks.mark(|m| m.dns_end = Some(Instant::now()));
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+// Wraps reqwest's connector to time how long a connection (TCP, proxy, and TLS) takes.
+#[derive(Debug, Clone)]
+pub struct TimedConnectLayer {
+    marks: TimingMarks,
+}
+
+impl<S> Layer<S> for TimedConnectLayer {
+    type Service = TimedConnect<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TimedConnect {
+            inner,
+            marks: self.marks.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimedConnect<S> {
+    inner: S,
+    marks: TimingMarks,
+}
+
+impl<S, R> Service<R> for TimedConnect<S>
+where
+    S: Service<R>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let marks = self.marks.clone();
+
+        marks.mark(|m| {
+            m.conn_start = Some(Instant::now());
+            m.tls_start = None;
+            m.conn_end = None;
+        });
+
+        let fut = self.inner.call(req);
+
+        Box::pin(async move {
+            let res = fut.await;
+
+            marks.mark(|m| m.conn_end = Some(Instant::now()));
+
+            res
+        })
+    }
+}
+
+// rustls asks its session store for a key exchange hint while building the ClientHello,
+// which happens as soon as the TCP connection is up. We use that to mark the TLS start.
+#[derive(Debug)]
+pub struct TimedSessionStore {
+    inner: ClientSessionMemoryCache,
+    marks: TimingMarks,
+}
+
+impl ClientSessionStore for TimedSessionStore {
+    fn set_kx_hint(&self, server_name: ServerName<'static>, group: NamedGroup) {
+        self.inner.set_kx_hint(server_name, group)
+    }
+
+    fn kx_hint(&self, server_name: &ServerName<'_>) -> Option<NamedGroup> {
+        self.marks.mark(|m| {
+            m.tls_start.get_or_insert(Instant::now());
+        });
+
+        self.inner.kx_hint(server_name)
+    }
+
+    fn set_tls12_session(&self, server_name: ServerName<'static>, value: Tls12ClientSessionValue) {
+        self.inner.set_tls12_session(server_name, value)
+    }
+
+    fn tls12_session(&self, server_name: &ServerName<'_>) -> Option<Tls12ClientSessionValue> {
+        self.inner.tls12_session(server_name)
+    }
+
+    fn remove_tls12_session(&self, server_name: &ServerName<'static>) {
+        self.inner.remove_tls12_session(server_name)
+    }
+
+    fn insert_tls13_ticket(
+        &self,
+        server_name: ServerName<'static>,
+        value: Tls13ClientSessionValue,
+    ) {
+        self.inner.insert_tls13_ticket(server_name, value)
+    }
+
+    fn take_tls13_ticket(
+        &self,
+        server_name: &ServerName<'static>,
+    ) -> Option<Tls13ClientSessionValue> {
+        self.inner.take_tls13_ticket(server_name)
+    }
+}
@@ -0,0 +1,442 @@
+use std::fs;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ::aws_lc_rs::digest;
+use anyhow::{Result, anyhow};
+use base64::prelude::{BASE64_STANDARD, Engine};
+
+use rustls::client::WebPkiServerVerifier;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{CryptoProvider, aws_lc_rs, verify_tls12_signature, verify_tls13_signature};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme};
+
+use x509_parser::extensions::GeneralName;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+pub fn crypto_provider() -> Arc<CryptoProvider> {
+    Arc::new(aws_lc_rs::default_provider())
+}
+
+// Loads the system's root certificates, or the certificates from a PEM bundle if one is given.
+pub fn load_roots(ca_file: Option<&str>) -> Result<RootCertStore> {
+    let mut roots = RootCertStore::empty();
+
+    match ca_file {
+        Some(ca_file) => {
+            let contents = match fs::read(ca_file) {
+                Ok(contents) => contents,
+                Err(e) => return Err(anyhow!("Failed to read CA file {}: {}", ca_file, e)),
+            };
+
+            for cert in CertificateDer::pem_slice_iter(&contents) {
+                roots.add(cert?)?;
+            }
+        }
+        None => {
+            let res = rustls_native_certs::load_native_certs();
+
+            roots.add_parsable_certificates(res.certs);
+        }
+    }
+
+    if roots.is_empty() {
+        return Err(anyhow!("No root certificates found"));
+    }
+
+    Ok(roots)
+}
+
+// A verifier that records certificate errors instead of aborting the handshake.
+// This lets checks inspect the peer's certificate even when it doesn't validate.
+#[derive(Debug)]
+pub struct RecordingVerifier {
+    inner: Arc<WebPkiServerVerifier>,
+    err: Mutex<Option<rustls::Error>>,
+}
+
+impl RecordingVerifier {
+    pub fn new(roots: RootCertStore) -> Result<Arc<Self>> {
+        let inner = WebPkiServerVerifier::builder_with_provider(Arc::new(roots), crypto_provider())
+            .build()?;
+
+        Ok(Arc::new(Self {
+            inner,
+            err: Mutex::new(None),
+        }))
+    }
+
+    pub fn take_err(&self) -> Option<rustls::Error> {
+        self.err.lock().unwrap().take()
+    }
+}
+
+impl ServerCertVerifier for RecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if let Err(e) = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        ) {
+            *self.err.lock().unwrap() = Some(e);
+        }
+
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+pub fn client_config(verifier: Arc<RecordingVerifier>) -> Result<ClientConfig> {
+    Ok(ClientConfig::builder_with_provider(crypto_provider())
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(verifier)
+        .with_no_client_auth())
+}
+
+#[derive(Debug, Clone)]
+pub struct CertInfo {
+    pub not_after: String,
+    pub days_left: i64,
+    pub sans: Vec<String>,
+}
+
+pub fn cert_info(der: &[u8]) -> Result<CertInfo> {
+    let (_, cert) = match X509Certificate::from_der(der) {
+        Ok(res) => res,
+        Err(e) => return Err(anyhow!("Failed to parse certificate: {}", e)),
+    };
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    let not_after = cert.validity().not_after;
+
+    // Round down so a certificate expiring in 12 hours has 0 days left.
+    let days_left = (not_after.timestamp() - now).div_euclid(86400);
+
+    let mut sans: Vec<String> = Vec::new();
+
+    if let Ok(Some(ext)) = cert.subject_alternative_name() {
+        for name in ext.value.general_names.iter() {
+            match name {
+                GeneralName::DNSName(dns) => sans.push(dns.to_lowercase()),
+                GeneralName::IPAddress(ip) => {
+                    if let Ok(octets) = <[u8; 4]>::try_from(*ip) {
+                        sans.push(Ipv4Addr::from(octets).to_string());
+                    } else if let Ok(octets) = <[u8; 16]>::try_from(*ip) {
+                        sans.push(Ipv6Addr::from(octets).to_string());
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    Ok(CertInfo {
+        not_after: not_after.to_string(),
+        days_left,
+        sans,
+    })
+}
+
+// Fails if the certificate is expired (or about to), doesn't match the name, or didn't validate.
+pub fn check_cert(
+    info: &CertInfo,
+    name: &str,
+    verifier: &RecordingVerifier,
+    is_insecure: bool,
+    fail_days: i64,
+) -> Result<()> {
+    if info.days_left < 0 {
+        return Err(anyhow!(
+            "Certificate for {} expired on {}",
+            name,
+            info.not_after
+        ));
+    }
+
+    if info.days_left <= fail_days {
+        return Err(anyhow!(
+            "Certificate for {} expires in {} days on {} (fail at {} days)",
+            name,
+            info.days_left,
+            info.not_after,
+            fail_days
+        ));
+    }
+
+    if !info.sans.iter().any(|san| san_matches(san, name)) {
+        return Err(anyhow!(
+            "Certificate for {} doesn't match hostname (SANs: {})",
+            name,
+            info.sans.join(", ")
+        ));
+    }
+
+    if !is_insecure && let Some(e) = verifier.take_err() {
+        return Err(anyhow!("Certificate validation for {} failed: {}", name, e));
+    }
+
+    Ok(())
+}
+
+// Checks whether a name is covered by a SAN entry (including single-label wildcards).
+pub fn san_matches(san: &str, name: &str) -> bool {
+    let name = name.trim_end_matches('.').to_lowercase();
+
+    match san.strip_prefix("*.") {
+        Some(suffix) => match name.split_once('.') {
+            Some((_, rest)) => rest == suffix,
+            None => false,
+        },
+        None => san == name,
+    }
+}
+
+// Extra TLS settings for clients we build (HTTP, gRPC, and Postgres).
+// The config doesn't offer any ALPN protocols, so callers set their own.
+// Files are read every time a client is built so renewed certificates are picked up.
+#[derive(Debug, Clone, Default)]
+pub struct ClientTls {
+    pub ca_file: Option<String>,
+
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+
+    // SHA-256 hashes of the server's SPKI (any one must match).
+    pub pins: Vec<Vec<u8>>,
+}
+
+impl ClientTls {
+    pub fn is_empty(&self) -> bool {
+        self.ca_file.is_none()
+            && self.client_cert.is_none()
+            && self.client_key.is_none()
+            && self.pins.is_empty()
+    }
+
+    // Accepts pins as base64 with an optional "sha256/" prefix (e.g. "sha256//AbC...=").
+    pub fn parse_pin(pin: &str) -> Result<Vec<u8>> {
+        let encoded = pin.trim_start_matches("sha256/").trim_start_matches('/');
+
+        let hash = match BASE64_STANDARD.decode(encoded) {
+            Ok(hash) => hash,
+            Err(e) => return Err(anyhow!("Invalid certificate pin {}: {}", pin, e)),
+        };
+
+        if hash.len() != 32 {
+            return Err(anyhow!(
+                "Invalid certificate pin {}: expected a SHA-256 hash",
+                pin
+            ));
+        }
+
+        Ok(hash)
+    }
+
+    pub fn client_config(&self, is_insecure: bool) -> Result<ClientConfig> {
+        let inner = match is_insecure {
+            true => None,
+            false => Some(
+                WebPkiServerVerifier::builder_with_provider(
+                    Arc::new(load_roots(self.ca_file.as_deref())?),
+                    crypto_provider(),
+                )
+                .build()?,
+            ),
+        };
+
+        let verifier = Arc::new(PinningVerifier {
+            inner,
+            pins: self.pins.clone(),
+            provider: crypto_provider(),
+        });
+
+        let builder = ClientConfig::builder_with_provider(crypto_provider())
+            .with_safe_default_protocol_versions()?
+            .dangerous()
+            .with_custom_certificate_verifier(verifier);
+
+        let cfg = match (&self.client_cert, &self.client_key) {
+            (Some(cert_file), key_file) => {
+                let certs = Self::read_certs(cert_file)?;
+
+                // The key may be bundled in the same file as the certificate.
+                let key_file = key_file.as_ref().unwrap_or(cert_file);
+
+                let key = match PrivateKeyDer::from_pem_file(key_file) {
+                    Ok(key) => key,
+                    Err(e) => {
+                        return Err(anyhow!("Failed to read client key {}: {}", key_file, e));
+                    }
+                };
+
+                builder.with_client_auth_cert(certs, key)?
+            }
+            (None, Some(_)) => return Err(anyhow!("A client key requires a client certificate")),
+            (None, None) => builder.with_no_client_auth(),
+        };
+
+        Ok(cfg)
+    }
+
+    fn read_certs(file: &str) -> Result<Vec<CertificateDer<'static>>> {
+        let certs = match CertificateDer::pem_file_iter(file) {
+            Ok(certs) => certs.collect::<Result<Vec<_>, _>>(),
+            Err(e) => return Err(anyhow!("Failed to read client certificate {}: {}", file, e)),
+        };
+
+        match certs {
+            Ok(certs) if !certs.is_empty() => Ok(certs),
+            Ok(_) => Err(anyhow!("No certificates found in {}", file)),
+            Err(e) => Err(anyhow!("Failed to read client certificate {}: {}", file, e)),
+        }
+    }
+}
+
+// Validates the chain (unless insecure) and then checks the leaf's SPKI against our pins.
+#[derive(Debug)]
+struct PinningVerifier {
+    inner: Option<Arc<WebPkiServerVerifier>>,
+    pins: Vec<Vec<u8>>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for PinningVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if let Some(inner) = &self.inner {
+            inner.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)?;
+        }
+
+        if self.pins.is_empty() {
+            return Ok(ServerCertVerified::assertion());
+        }
+
+        let spki = match X509Certificate::from_der(end_entity) {
+            Ok((_, cert)) => cert.tbs_certificate.subject_pki.raw.to_vec(),
+            Err(e) => {
+                return Err(rustls::Error::General(format!(
+                    "Invalid certificate: {}",
+                    e
+                )));
+            }
+        };
+
+        let hash = digest::digest(&digest::SHA256, &spki);
+
+        if !self.pins.iter().any(|pin| pin.as_slice() == hash.as_ref()) {
+            return Err(rustls::Error::General(format!(
+                "Certificate pin mismatch (got sha256/{})",
+                BASE64_STANDARD.encode(hash.as_ref())
+            )));
+        }
+
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A SHA-256 hash of all 0x01 bytes, base64-encoded.
+    const PIN: &str = "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=";
+
+    #[test]
+    fn parses_pins() {
+        assert_eq!(ClientTls::parse_pin(PIN).unwrap(), [1u8; 32]);
+        assert_eq!(
+            ClientTls::parse_pin(&format!("sha256/{}", PIN)).unwrap(),
+            [1u8; 32]
+        );
+        assert_eq!(
+            ClientTls::parse_pin(&format!("sha256//{}", PIN)).unwrap(),
+            [1u8; 32]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_pins() {
+        assert!(ClientTls::parse_pin("").is_err());
+        assert!(ClientTls::parse_pin("not base64!").is_err());
+
+        // Valid base64, but a SHA-1 sized hash.
+        assert!(ClientTls::parse_pin("AQEBAQEBAQEBAQEBAQEBAQEBAQE=").is_err());
+
+        // Hex isn't accepted.
+        assert!(ClientTls::parse_pin(&"01".repeat(32)).is_err());
+    }
+}
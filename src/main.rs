@@ -14,11 +14,16 @@ use check::{Check, CheckType};
 use debugger::{LogLevel, Logger};
 use service::Service;
 
-use helper::HttpMethod;
+use helper::tls::ClientTls;
+use helper::{HttpCapture, Secret, decode_hex};
 
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use hickory_proto::rr::{Name, RecordType};
+use regex::Regex;
+use regex::bytes::RegexBuilder;
 use tokio_cron_scheduler::JobScheduler;
 
 use clap::Parser;
@@ -67,18 +72,366 @@ async fn main() -> Result<()> {
             config::CheckType::HTTP => {
                 let http: config::HttpCheckConfig = cfg_check.clone().http.unwrap();
 
-                CheckType::Http(check::HttpCheck {
-                    method: HttpMethod::from_str(http.method.as_str()),
-                    url: http.url.clone(),
-                    timeout: http.timeout.into(),
+                CheckType::Http(Box::new(check::HttpCheck::from_config(
+                    &cfg_service.name,
+                    &http,
+                )?))
+            }
+            config::CheckType::TCP => {
+                let tcp: config::TcpCheckConfig = cfg_check.clone().tcp.unwrap();
+
+                CheckType::Tcp(check::TcpCheck {
+                    host: tcp.host.clone(),
+                    port: tcp.port,
+                    timeout: tcp.timeout,
+
+                    send: tcp.send.clone(),
+                    expect: match &tcp.expect {
+                        Some(expect) => Some(Regex::new(expect)?),
+                        None => None,
+                    },
+
+                    read_max: tcp.read_max,
+                })
+            }
+            config::CheckType::UDP => {
+                let udp: config::UdpCheckConfig = cfg_check.clone().udp.unwrap();
+
+                CheckType::Udp(check::UdpCheck {
+                    host: udp.host.clone(),
+                    port: udp.port,
+                    timeout: udp.timeout,
+
+                    send: match udp.send_is_hex {
+                        true => decode_hex(&udp.send)?,
+                        false => udp.send.clone().into_bytes(),
+                    },
+                    expect: match &udp.expect {
+                        Some(expect) => Some(RegexBuilder::new(expect).unicode(false).build()?),
+                        None => None,
+                    },
+                })
+            }
+            config::CheckType::ICMP => {
+                let icmp: config::IcmpCheckConfig = cfg_check.clone().icmp.unwrap();
+
+                // Loss and RTT are worked out from the replies, so at least one ping is needed.
+                if icmp.count == 0 {
+                    return Err(anyhow!(
+                        "ICMP check for service {} needs a count of at least 1",
+                        cfg_service.name
+                    ));
+                }
+
+                CheckType::Icmp(check::IcmpCheck {
+                    host: icmp.host.clone(),
+
+                    count: icmp.count,
+                    interval: icmp.interval,
+                    timeout: icmp.timeout,
+
+                    max_loss: icmp.max_loss,
+                    max_rtt_avg: icmp.max_rtt_avg,
+                })
+            }
+            config::CheckType::DNS => {
+                let dns: config::DnsCheckConfig = cfg_check.clone().dns.unwrap();
+
+                CheckType::Dns(check::DnsCheck {
+                    server: dns.server.parse()?,
+
+                    name: Name::from_utf8(&dns.name)?,
+                    record_type: RecordType::from_str(&dns.record_type.to_uppercase())?,
+
+                    timeout: dns.timeout,
+
+                    recursion: dns.recursion,
+
+                    rcode: check::DnsCheck::parse_rcode(&dns.rcode)?,
+                    answers: dns.answers.clone(),
+                    max_query_time: dns.max_query_time,
+                })
+            }
+            config::CheckType::TLS => {
+                let tls: config::TlsCheckConfig = cfg_check.clone().tls.unwrap();
+
+                CheckType::Tls(check::TlsCheck {
+                    host: tls.host.clone(),
+                    port: tls.port,
+                    sni: tls.sni.clone(),
+
+                    timeout: tls.timeout,
+
+                    is_insecure: tls.is_insecure,
+                    ca_file: tls.ca_file.clone(),
+
+                    warn_days: tls.warn_days,
+                    fail_days: tls.fail_days,
+
+                    sans: tls.sans.clone(),
+                })
+            }
+            config::CheckType::EXEC => {
+                let exec: config::ExecCheckConfig = cfg_check.clone().exec.unwrap();
+
+                CheckType::Exec(check::ExecCheck {
+                    program: exec.program.clone(),
+                    args: exec.args.clone(),
+
+                    env: exec.env.as_ref().map(|env| {
+                        env.iter()
+                            .map(|(key, val)| (key.clone(), Secret::from(val.clone())))
+                            .collect()
+                    }),
+                    cwd: exec.cwd.clone(),
+
+                    timeout: exec.timeout,
+
+                    exit_codes: exec.exit_codes.clone(),
+
+                    stdout: match &exec.stdout {
+                        Some(expect) => Some(Regex::new(expect)?),
+                        None => None,
+                    },
+                    stderr: match &exec.stderr {
+                        Some(expect) => Some(Regex::new(expect)?),
+                        None => None,
+                    },
+                })
+            }
+            config::CheckType::PROCESS => {
+                let process: config::ProcessCheckConfig = cfg_check.clone().process.unwrap();
+
+                // Without either, there'd be nothing to look for.
+                if process.pattern.is_none() && process.pidfile.is_none() {
+                    return Err(anyhow!(
+                        "Process check for service {} needs a pattern or a pidfile",
+                        cfg_service.name
+                    ));
+                }
+
+                CheckType::Process(check::ProcessCheck {
+                    pattern: match &process.pattern {
+                        Some(pattern) => Some(Regex::new(pattern)?),
+                        None => None,
+                    },
+                    match_cmdline: process.match_cmdline,
+
+                    min: process.min,
+                    max: process.max,
+
+                    max_rss: process.max_rss,
+                    max_cpu_time: process.max_cpu_time,
+
+                    pidfile: process.pidfile.clone(),
+                })
+            }
+            config::CheckType::DISK => {
+                let disk: config::DiskCheckConfig = cfg_check.clone().disk.unwrap();
+
+                CheckType::Disk(check::DiskCheck {
+                    mounts: disk.mounts.clone(),
 
-                    body: http.body.clone(),
-                    body_is_file: http.body_is_file,
+                    warn_used_pct: disk.warn_used_pct,
+                    fail_used_pct: disk.fail_used_pct,
 
-                    headers: http.headers.clone(),
-                    is_insecure: http.is_insecure,
+                    warn_free_mb: disk.warn_free_mb,
+                    fail_free_mb: disk.fail_free_mb,
+
+                    warn_inodes_pct: disk.warn_inodes_pct,
+                    fail_inodes_pct: disk.fail_inodes_pct,
+                })
+            }
+            config::CheckType::SYSTEM => {
+                let system: config::SystemCheckConfig = cfg_check.clone().system.unwrap();
+
+                CheckType::System(check::SystemCheck {
+                    load_avg: check::SystemCheck::parse_load_avg(system.load_avg)?,
+
+                    warn_load_per_core: system.warn_load_per_core,
+                    fail_load_per_core: system.fail_load_per_core,
+
+                    warn_mem_avail_pct: system.warn_mem_avail_pct,
+                    fail_mem_avail_pct: system.fail_mem_avail_pct,
+
+                    warn_swap_used_pct: system.warn_swap_used_pct,
+                    fail_swap_used_pct: system.fail_swap_used_pct,
+
+                    pressure_avg: check::SystemCheck::parse_pressure_avg(system.pressure_avg)?,
+
+                    warn_cpu_pressure: system.warn_cpu_pressure,
+                    fail_cpu_pressure: system.fail_cpu_pressure,
+
+                    warn_mem_pressure: system.warn_mem_pressure,
+                    fail_mem_pressure: system.fail_mem_pressure,
+
+                    warn_io_pressure: system.warn_io_pressure,
+                    fail_io_pressure: system.fail_io_pressure,
+                })
+            }
+            config::CheckType::FILE => {
+                let file: config::FileCheckConfig = cfg_check.clone().file.unwrap();
+
+                CheckType::File(check::FileCheck {
+                    path: file.path.clone(),
+
+                    max_age: file.max_age,
+
+                    min_size: file.min_size,
+                    max_size: file.max_size,
+                })
+            }
+            config::CheckType::HTTP_FLOW => {
+                let flow: config::HttpFlowCheckConfig = cfg_check.clone().http_flow.unwrap();
+
+                CheckType::HttpFlow(check::HttpFlowCheck {
+                    steps: flow
+                        .steps
+                        .iter()
+                        .enumerate()
+                        .map(|(i, step)| {
+                            Ok(check::HttpFlowStep {
+                                name: step.name.clone().unwrap_or((i + 1).to_string()),
+
+                                check: check::HttpCheck::from_config(
+                                    &cfg_service.name,
+                                    &step.http,
+                                )?,
+
+                                captures: step
+                                    .capture
+                                    .iter()
+                                    .map(|(var, expr)| Ok((var.clone(), HttpCapture::parse(expr)?)))
+                                    .collect::<Result<Vec<_>>>()?,
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                })
+            }
+            config::CheckType::REDIS => {
+                let redis: config::RedisCheckConfig = cfg_check.clone().redis.unwrap();
+
+                CheckType::Redis(check::RedisCheck {
+                    host: redis.host.clone(),
+                    port: redis.port,
+                    timeout: redis.timeout,
+
+                    username: redis.username.clone(),
+                    password: redis.password.clone().map(Secret::from),
+
+                    role: redis.role.clone(),
+
+                    min_replicas: redis.min_replicas,
+
+                    warn_used_memory_mb: redis.warn_used_memory_mb,
+                    fail_used_memory_mb: redis.fail_used_memory_mb,
+
+                    warn_blocked_clients: redis.warn_blocked_clients,
+                    fail_blocked_clients: redis.fail_blocked_clients,
+                })
+            }
+            config::CheckType::POSTGRES => {
+                let postgres: config::PostgresCheckConfig = cfg_check.clone().postgres.unwrap();
+
+                CheckType::Postgres(check::PostgresCheck {
+                    host: postgres.host.clone(),
+                    port: postgres.port,
+                    timeout: postgres.timeout,
+
+                    user: postgres.user.clone(),
+                    password: postgres.password.clone().map(Secret::from),
+                    database: postgres.database.clone(),
+
+                    tls_mode: check::PostgresTlsMode::from_string(&postgres.tls_mode)?,
+                    tls: ClientTls {
+                        ca_file: postgres.ca_file.clone(),
+                        client_cert: postgres.client_cert.clone(),
+                        client_key: postgres.client_key.clone(),
+                        pins: Vec::new(),
+                    },
+
+                    query: postgres.query.clone(),
+
+                    expect: postgres.expect.clone(),
+
+                    warn_min: postgres.warn_min,
+                    fail_min: postgres.fail_min,
+
+                    warn_max: postgres.warn_max,
+                    fail_max: postgres.fail_max,
+                })
+            }
+            config::CheckType::MYSQL => {
+                let mysql: config::MysqlCheckConfig = cfg_check.clone().mysql.unwrap();
+
+                CheckType::Mysql(check::MysqlCheck {
+                    host: mysql.host.clone(),
+                    port: mysql.port,
+                    timeout: mysql.timeout,
+
+                    user: mysql.user.clone(),
+                    password: mysql.password.clone().map(Secret::from),
+                    database: mysql.database.clone(),
+
+                    query: mysql.query.clone(),
+                    expect: mysql
+                        .expect
+                        .iter()
+                        .map(|expr| check::MysqlAssert::parse(expr))
+                        .collect::<Result<Vec<_>>>()?,
+                })
+            }
+            config::CheckType::SMTP => {
+                let smtp: config::SmtpCheckConfig = cfg_check.clone().smtp.unwrap();
+
+                CheckType::Smtp(check::SmtpCheck {
+                    host: smtp.host.clone(),
+                    port: smtp.port,
+                    timeout: smtp.timeout,
+
+                    ehlo_name: smtp.ehlo_name.clone(),
+
+                    expect_extensions: smtp.expect_extensions.clone(),
+
+                    starttls: smtp.starttls,
+                    sni: smtp.sni.clone(),
+
+                    is_insecure: smtp.is_insecure,
+                    ca_file: smtp.ca_file.clone(),
+
+                    warn_days: smtp.warn_days,
+                    fail_days: smtp.fail_days,
+
+                    username: smtp.username.clone(),
+                    password: smtp.password.clone().map(Secret::from),
+
+                    allow_plaintext_auth: smtp.allow_plaintext_auth,
+                })
+            }
+            config::CheckType::GRPC => {
+                let grpc: config::GrpcCheckConfig = cfg_check.clone().grpc.unwrap();
+
+                CheckType::Grpc(check::GrpcCheck {
+                    host: grpc.host.clone(),
+                    port: grpc.port,
+                    timeout: grpc.timeout,
+
+                    service: grpc.service.clone(),
+
+                    tls: grpc.tls,
+                    is_insecure: grpc.is_insecure,
+                    tls_cfg: ClientTls {
+                        ca_file: grpc.ca_file.clone(),
+                        client_cert: grpc.client_cert.clone(),
+                        client_key: grpc.client_key.clone(),
+                        pins: Vec::new(),
+                    },
 
-                    accept_codes: http.accept_codes,
+                    metadata: grpc
+                        .metadata
+                        .iter()
+                        .map(|(key, val)| (key.clone(), Secret::from(val.clone())))
+                        .collect(),
                 })
             }
         };
@@ -95,15 +448,7 @@ async fn main() -> Result<()> {
                     config::AlertType::HTTP => {
                         let http = alert_pass_cfg.clone().http.unwrap();
 
-                        AlertType::Http(HttpAlert::new(
-                            HttpMethod::from_str(http.method.as_str()),
-                            http.url.clone(),
-                            http.timeout,
-                            http.body.clone(),
-                            http.body_is_file,
-                            http.headers.clone(),
-                            http.is_insecure,
-                        ))
+                        AlertType::Http(HttpAlert::from_config(&cfg_service.name, &http)?)
                     }
                 },
             });
@@ -118,15 +463,7 @@ async fn main() -> Result<()> {
                     config::AlertType::HTTP => {
                         let http = alert_fail_cfg.clone().http.unwrap();
 
-                        AlertType::Http(HttpAlert::new(
-                            HttpMethod::from_str(http.method.as_str()),
-                            http.url.clone(),
-                            http.timeout,
-                            http.body.clone(),
-                            http.body_is_file,
-                            http.headers.clone(),
-                            http.is_insecure,
-                        ))
+                        AlertType::Http(HttpAlert::from_config(&cfg_service.name, &http)?)
                     }
                 },
             });
@@ -173,7 +510,7 @@ async fn main() -> Result<()> {
 
     let mut cont = true;
 
-    while cont == true {
+    while cont {
         tokio::select! {
             _ = async {
                 // If we're not in input mode, just sleep.
@@ -2,6 +2,7 @@ use crate::service::ServiceStatus;
 
 use crate::alert::Alert;
 use crate::check::Check;
+use crate::helper::timing::HttpTimings;
 
 use crate::debugger::{LogLevel, Logger};
 
@@ -23,6 +24,9 @@ pub struct Service {
     pub lats_max_track: u32,
     pub lats: Arc<Mutex<Vec<u32>>>,
 
+    // The timing breakdown from the last successful HTTP check.
+    pub timings: Arc<Mutex<Option<HttpTimings>>>,
+
     pub fails_cnt_to_alert: u32,
 
     pub check: Arc<Mutex<Check>>,
@@ -47,6 +51,7 @@ impl Service {
 
             lats_max_track: lats_max_track.unwrap_or(10),
             lats: Arc::new(Mutex::new(Vec::new())),
+            timings: Arc::new(Mutex::new(None)),
 
             check: Arc::new(Mutex::new(check)),
 
@@ -94,6 +99,7 @@ impl Service {
         // Create Arcs
         let status = self.status.clone();
         let lats = self.lats.clone();
+        let timings = self.timings.clone();
 
         let check = self.check.clone();
 
@@ -110,6 +116,7 @@ impl Service {
 
             let status = status.clone();
             let lats = lats.clone();
+            let timings = timings.clone();
 
             let check = check.clone();
 
@@ -162,36 +169,60 @@ impl Service {
                         check.fails_cur += 1;
                         check.fails_tot += 1;
                     }
-                    Ok(_) => {
-                        // Calculate latency now and push to vector.
-                        let mut lats = lats.lock().await;
-
+                    Ok(res) => {
                         // Calculate latency before anything for precision.
                         let lat = now.elapsed().as_millis() as u32;
 
-                        lats.push(lat);
+                        // Calculate latency now and push to vector.
+                        let mut lats = lats.lock().await;
+
+                        // Prefer latencies measured by the check itself (e.g. ICMP RTTs).
+                        match res.lats.is_empty() {
+                            true => lats.push(lat),
+                            false => lats.extend(res.lats),
+                        }
 
-                        // If we exceed max latency track, we need to remove oldest entry.
+                        // If we exceed max latency track, we need to remove oldest entries.
                         if lats_max_track > 0 && lats.len() > lats_max_track as usize {
-                            lats.remove(0);
+                            let excess = lats.len() - lats_max_track as usize;
+
+                            lats.drain(..excess);
                         }
 
                         // We no longer need to access lats lock.
                         drop(lats);
 
-                        // Quickly set state to healthy.
-                        *status.lock().await = ServiceStatus::HEALTHY;
+                        if res.timings.is_some() {
+                            *timings.lock().await = res.timings;
+                        }
 
-                        // If we weren't healthy before, we need to trigger alerts and check things.
-                        if old_status == ServiceStatus::HEALTHY {
-                            return;
+                        // Quickly set state to healthy (or warning if the check reported one).
+                        *status.lock().await = match res.warn {
+                            Some(_) => ServiceStatus::WARNING,
+                            None => ServiceStatus::HEALTHY,
+                        };
+
+                        // Only log when the state changes so we don't spam on every run.
+                        match &res.warn {
+                            Some(warn) if old_status != ServiceStatus::WARNING => logger.log(
+                                LogLevel::WARN,
+                                format!("{} has a warning: {}", name, warn).as_str(),
+                                false,
+                            ),
+                            None if old_status != ServiceStatus::HEALTHY => logger.log(
+                                LogLevel::INFO,
+                                format!("{} is now healthy!", name).as_str(),
+                                false,
+                            ),
+                            _ => (),
                         }
 
-                        logger.log(
-                            LogLevel::INFO,
-                            format!("{} is now healthy!", name).as_str(),
-                            false,
-                        );
+                        // If we were passing before, there's nothing to recover from.
+                        if old_status == ServiceStatus::HEALTHY
+                            || old_status == ServiceStatus::WARNING
+                        {
+                            return;
+                        }
 
                         // We need to trigger pass alert if enabled and if our current fail count exeeds the fail alert threshold (or 0 if none).
                         if let Some(alert) = alert_pass.as_ref()
@@ -222,11 +253,7 @@ impl Service {
 
         // Check for error creating job before we actually schedule it.
         if let Err(e) = job {
-            return Err(anyhow!(
-                "Unable to create job for {}: {}",
-                name,
-                e.to_string()
-            ));
+            return Err(anyhow!("Unable to create job for {}: {}", name, e));
         }
 
         if let Err(e) = sch.add(job.unwrap()).await {
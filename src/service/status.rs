@@ -7,6 +7,7 @@ pub enum ServiceStatus {
     INIT,
     HEALTHY,
     CHECKING,
+    WARNING,
     UNHEALTHY,
 }
 
@@ -19,6 +20,7 @@ impl fmt::Display for ServiceStatus {
                 ServiceStatus::INIT => "INIT",
                 ServiceStatus::HEALTHY => "HEALTHY",
                 ServiceStatus::CHECKING => "CHECKING",
+                ServiceStatus::WARNING => "WARNING",
                 ServiceStatus::UNHEALTHY => "UNHEALTHY",
             }
         )